@@ -1,18 +1,53 @@
+# Every feature that works on stable. `allocator_api` needs nightly, so
+# `--all-features` doesn't build on stable.
+FEATURES="alloc std derive serde serdejson querymap toml serde_yaml ciborium url http http-body axum clap"
+
 set -x
+# Each tier (`core` only, `alloc`, and `std`) must build on its own.
+cargo build --no-default-features && \
+cargo build --no-default-features --features alloc && \
+cargo build --no-default-features --features std && \
 cargo clippy && \
-cargo clippy --all-features && \
+cargo clippy --features "$FEATURES" && \
 cargo clippy --no-default-features && \
+cargo clippy --no-default-features --features alloc && \
 cargo clippy --no-default-features --features serdejson && \
 cargo clippy --no-default-features --features querymap && \
+cargo clippy --no-default-features --features derive && \
+cargo clippy --no-default-features --features serde && \
+cargo clippy --no-default-features --features toml && \
+cargo clippy --no-default-features --features serde_yaml && \
+cargo clippy --no-default-features --features ciborium && \
+cargo clippy --no-default-features --features url && \
+cargo clippy --no-default-features --features http && \
+cargo clippy --no-default-features --features http-body && \
+cargo clippy --no-default-features --features axum && \
+cargo clippy --no-default-features --features clap && \
 cargo clippy --tests && \
-cargo clippy --tests --all-features && \
+cargo clippy --tests --features "$FEATURES" && \
 cargo clippy --tests --no-default-features && \
+cargo clippy --tests --no-default-features --features alloc && \
 cargo clippy --tests --no-default-features --features serdejson && \
 cargo clippy --tests --no-default-features --features querymap && \
+cargo clippy --tests --no-default-features --features derive && \
+cargo clippy --tests --no-default-features --features serde && \
+cargo clippy --tests --no-default-features --features toml && \
+cargo clippy --tests --no-default-features --features serde_yaml && \
+cargo clippy --tests --no-default-features --features ciborium && \
+cargo clippy --tests --no-default-features --features url && \
+cargo clippy --tests --no-default-features --features http && \
+cargo clippy --tests --no-default-features --features http-body && \
+cargo clippy --tests --no-default-features --features axum && \
+cargo clippy --tests --no-default-features --features clap && \
+cargo clippy -p optempty-derive && \
 cargo test && \
-cargo test --all-features && \
-cargo test --no-default-features && \
-cargo doc --all-features
+cargo test --features "$FEATURES" && \
+cargo test --no-default-features --features alloc && \
+# The doc examples use `alloc` types, so only run the other tests for `core`.
+cargo test --no-default-features --tests && \
+cargo +nightly clippy --tests --features allocator_api && \
+cargo +nightly test --features allocator_api && \
+cargo doc --features "$FEATURES"
 
 cargo publish --dry-run -v --allow-dirty
 set +x
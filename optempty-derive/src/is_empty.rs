@@ -0,0 +1,149 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    parse_quote, Attribute, Data, DataEnum, DataStruct, DeriveInput, Error, Generics, Index,
+    Member, Meta, Path, Result,
+};
+
+/// How a single struct field takes part in deciding if the struct is empty.
+enum FieldCheck {
+    /// The default. Uses the field's own `IsEmpty` impl.
+    IsEmpty,
+    /// `#[is_empty(skip)]`. The field is ignored.
+    Skip,
+    /// `#[is_empty(with = path)]`. Calls `path(&field)`, which must return
+    /// `bool`.
+    With(Path),
+}
+
+pub(crate) fn derive(mut input: DeriveInput) -> Result<TokenStream> {
+    if let Some(attr) = find_attr(&input.attrs) {
+        return Err(Error::new_spanned(
+            attr,
+            "`#[is_empty]` is not supported on the type itself",
+        ));
+    }
+
+    let body = match &input.data {
+        Data::Struct(data) => struct_body(data, &mut input.generics)?,
+        Data::Enum(data) => enum_body(data)?,
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`IsEmpty` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::optempty::is_empty::IsEmpty for #ident #ty_generics #where_clause {
+            fn is_empty(&self) -> bool {
+                #body
+            }
+        }
+    })
+}
+
+/// A struct is empty when all of its (non-skipped) fields are empty.
+fn struct_body(data: &DataStruct, generics: &mut Generics) -> Result<TokenStream> {
+    let is_generic = !generics.params.is_empty();
+    let mut checks = Vec::new();
+
+    for (i, field) in data.fields.iter().enumerate() {
+        let member = field
+            .ident
+            .clone()
+            .map(Member::Named)
+            .unwrap_or_else(|| Member::Unnamed(Index::from(i)));
+
+        match field_check(&field.attrs)? {
+            FieldCheck::IsEmpty => {
+                if is_generic {
+                    let ty = &field.ty;
+                    generics
+                        .make_where_clause()
+                        .predicates
+                        .push(parse_quote!(#ty: ::optempty::is_empty::IsEmpty));
+                }
+
+                checks.push(quote!(::optempty::is_empty::IsEmpty::is_empty(&self.#member)));
+            }
+            FieldCheck::Skip => {}
+            FieldCheck::With(path) => checks.push(quote!(#path(&self.#member))),
+        }
+    }
+
+    if checks.is_empty() {
+        return Ok(quote!(true));
+    }
+
+    Ok(quote!(#(#checks)&&*))
+}
+
+/// An enum is empty when it is one of the variants marked `#[is_empty]`.
+fn enum_body(data: &DataEnum) -> Result<TokenStream> {
+    let mut arms = Vec::new();
+
+    for variant in &data.variants {
+        if let Some(attr) = variant.fields.iter().find_map(|f| find_attr(&f.attrs)) {
+            return Err(Error::new_spanned(
+                attr,
+                "`#[is_empty]` is not supported on enum fields; mark the variant instead",
+            ));
+        }
+
+        let is_empty = match find_attr(&variant.attrs) {
+            Some(attr) => match &attr.meta {
+                Meta::Path(_) => true,
+                _ => {
+                    return Err(Error::new_spanned(
+                        attr,
+                        "expected a bare `#[is_empty]` on enum variants",
+                    ))
+                }
+            },
+            None => false,
+        };
+
+        let ident = &variant.ident;
+        arms.push(quote!(Self::#ident { .. } => #is_empty,));
+    }
+
+    Ok(quote! {
+        match *self {
+            #(#arms)*
+        }
+    })
+}
+
+fn field_check(attrs: &[Attribute]) -> Result<FieldCheck> {
+    let mut check = FieldCheck::IsEmpty;
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("is_empty")) {
+        attr.parse_nested_meta(|meta| {
+            if !matches!(check, FieldCheck::IsEmpty) {
+                return Err(meta.error("only one of `skip` or `with` may be given"));
+            }
+
+            if meta.path.is_ident("skip") {
+                check = FieldCheck::Skip;
+                Ok(())
+            } else if meta.path.is_ident("with") {
+                check = FieldCheck::With(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `skip` or `with = path`"))
+            }
+        })?;
+    }
+
+    Ok(check)
+}
+
+fn find_attr(attrs: &[Attribute]) -> Option<&Attribute> {
+    attrs.iter().find(|a| a.path().is_ident("is_empty"))
+}
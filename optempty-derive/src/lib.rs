@@ -0,0 +1,37 @@
+//! Derive and attribute macros for the [`optempty`](https://docs.rs/optempty/) crate.
+//!
+//! Don't depend on this crate directly. Enable the `derive` feature of
+//! `optempty` instead, which re-exports everything here.
+
+#![deny(warnings)]
+
+mod is_empty;
+mod omit_empty;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives `optempty::IsEmpty` for a struct or enum.
+///
+/// See the documentation on `optempty::IsEmpty` for details.
+#[proc_macro_derive(IsEmpty, attributes(is_empty))]
+pub fn derive_is_empty(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    is_empty::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Adds `#[serde(skip_serializing_if = ...)]` to every named field, so that
+/// empty ones are left out.
+///
+/// See the documentation on `optempty::omit_empty` for details.
+#[proc_macro_attribute]
+pub fn omit_empty(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    omit_empty::expand(args.into(), input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
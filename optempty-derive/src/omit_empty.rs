@@ -0,0 +1,194 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt, parse::Parser, parse_quote, punctuated::Punctuated, Attribute, Data,
+    DeriveInput, Error, Field, Meta, Result, Token,
+};
+
+/// What `#[omit_empty(...)]` on the type asked for.
+#[derive(Default)]
+struct Options {
+    /// `#[omit_empty(default)]`. Also adds `#[serde(default)]` to each field,
+    /// so that the omitted fields can be deserialized again.
+    default: bool,
+}
+
+pub(crate) fn expand(args: TokenStream, mut input: DeriveInput) -> Result<TokenStream> {
+    let options = parse_options(args)?;
+    let mut helpers = Vec::new();
+
+    match &mut input.data {
+        Data::Struct(data) => {
+            for field in &mut data.fields {
+                if let Some(helper) = omit_field(field, None, &options)? {
+                    helpers.push(helper);
+                }
+            }
+        }
+        Data::Enum(data) => {
+            for (v, variant) in data.variants.iter_mut().enumerate() {
+                for (f, field) in variant.fields.iter_mut().enumerate() {
+                    if let Some(helper) = omit_field(field, Some((v, f)), &options)? {
+                        helpers.push(helper);
+                    }
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`#[omit_empty]` is not supported on unions",
+            ))
+        }
+    }
+
+    if helpers.is_empty() {
+        return Ok(quote!(#input));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #input
+
+        #[automatically_derived]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #(#helpers)*
+        }
+    })
+}
+
+fn parse_options(args: TokenStream) -> Result<Options> {
+    let mut options = Options::default();
+
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("default") {
+            options.default = true;
+            Ok(())
+        } else {
+            Err(meta.error("expected `default`"))
+        }
+    });
+    parser.parse2(args)?;
+
+    Ok(options)
+}
+
+/// Adds `skip_serializing_if` to a named field, unless it's marked
+/// `#[omit_empty(keep)]` or already says how it's serialized. Returns the
+/// helper function that `skip_serializing_if` calls.
+///
+/// `variant` is the index of the field's variant, and of the field within it,
+/// for enums. Variant and field names can't be used there, since joining them
+/// can give the same name twice, e.g., `A_b` and `c`, or `A` and `b_c`.
+fn omit_field(
+    field: &mut Field,
+    variant: Option<(usize, usize)>,
+    options: &Options,
+) -> Result<Option<TokenStream>> {
+    let keep = take_keep(&mut field.attrs)?;
+
+    // Leaving out an element of a tuple struct or variant would shift the
+    // ones after it, so they're always serialized.
+    let Some(name) = &field.ident else {
+        return match keep {
+            Some(attr) => Err(Error::new_spanned(
+                attr,
+                "`#[omit_empty]` is only supported on named fields",
+            )),
+            None => Ok(None),
+        };
+    };
+
+    if keep.is_some() {
+        return Ok(None);
+    }
+
+    let serde = serde_args(&field.attrs);
+
+    if options.default && !serde.iter().any(|p| p == "default") {
+        field.attrs.push(parse_quote!(#[serde(default)]));
+    }
+
+    if serde
+        .iter()
+        .any(|p| p == "skip" || p == "skip_serializing" || p == "skip_serializing_if")
+    {
+        return Ok(None);
+    }
+
+    let helper = match variant {
+        Some((variant, field)) => format_ident!("__optempty_omit_empty_{}_{}", variant, field),
+        None => format_ident!("__optempty_omit_empty_{}", name.unraw()),
+    };
+    let path = format!("Self::{helper}");
+    field
+        .attrs
+        .push(parse_quote!(#[serde(skip_serializing_if = #path)]));
+
+    // A field serialized some other way is only checked with `IsEmpty`, since
+    // how its type serializes says nothing about what's written.
+    //
+    // Otherwise, the bound lets a field whose type is a generic parameter be
+    // checked by how it serializes. serde adds the same bound to its impl, so
+    // it always holds there.
+    let ty = &field.ty;
+    let (bound, serialize_probe) = if serde.iter().any(|p| p == "serialize_with" || p == "with") {
+        (None, None)
+    } else {
+        (
+            Some(quote!(where #ty: ::optempty::serde::__private::Serialize)),
+            Some(quote!(SerializeProbe as _,)),
+        )
+    };
+
+    Ok(Some(quote! {
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        fn #helper(value: &#ty) -> bool #bound {
+            #[allow(unused_imports)]
+            use ::optempty::serde::__private::{
+                IsEmptyProbe as _, NotEmptyProbe as _, #serialize_probe
+            };
+
+            (&&&::optempty::serde::__private::Probe(value)).__optempty_is_empty()
+        }
+    }))
+}
+
+/// Removes any `#[omit_empty(keep)]` from the field, returning it if there was
+/// one.
+fn take_keep(attrs: &mut Vec<Attribute>) -> Result<Option<Attribute>> {
+    let mut keep = None;
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("omit_empty")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("keep") {
+                Ok(())
+            } else {
+                Err(meta.error("expected `keep`"))
+            }
+        })?;
+        keep = Some(attr.clone());
+    }
+
+    attrs.retain(|a| !a.path().is_ident("omit_empty"));
+
+    Ok(keep)
+}
+
+/// The names of the arguments given in the field's `#[serde(...)]`
+/// attributes, such as `default` or `skip_serializing_if`.
+fn serde_args(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|a| a.path().is_ident("serde"))
+        .filter_map(|a| {
+            a.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .filter_map(|meta| meta.path().get_ident().map(ToString::to_string))
+        .collect()
+}
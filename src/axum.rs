@@ -0,0 +1,236 @@
+//! [axum] extractors that reject, or skip, empty input.
+//!
+//! * [`NonEmpty`] rejects the request with `422 Unprocessable Entity` if the
+//!   extracted value is empty.
+//! * [`EmptyAsNone`] gives `None` if the extracted value is empty.
+//!
+//! Both work with any extractor that implements [`IsEmpty`]. That includes
+//! [`Json<T>`], [`Query<T>`], and [`Form<T>`] where `T` implements
+//! [`IsEmpty`].
+//!
+//! # Examples
+//!
+//! ```
+//! use std::collections::HashMap;
+//!
+//! use axum::{extract::Query, routing::get, Json, Router};
+//! use optempty::axum::{EmptyAsNone, NonEmpty};
+//!
+//! async fn search(EmptyAsNone(query): EmptyAsNone<Query<HashMap<String, String>>>) -> String {
+//!     match query {
+//!         Some(Query(params)) => format!("searching {} params", params.len()),
+//!         None => "showing everything".to_owned(),
+//!     }
+//! }
+//!
+//! async fn delete(NonEmpty(Json(ids)): NonEmpty<Json<Vec<u32>>>) -> String {
+//!     format!("deleting {} ids", ids.len())
+//! }
+//!
+//! let app: Router = Router::new().route("/", get(search).delete(delete));
+//! ```
+//!
+//! [axum]: ::axum
+//! [`IsEmpty`]: crate::is_empty::IsEmpty
+//! [`Json<T>`]: ::axum::Json
+//! [`Query<T>`]: ::axum::extract::Query
+//! [`Form<T>`]: ::axum::Form
+
+use std::{error::Error, fmt};
+
+use axum::{
+    body::HttpBody,
+    extract::{FromRequest, FromRequestParts, Request},
+    http::{request::Parts, Method, StatusCode},
+    response::{IntoResponse, Response},
+};
+
+use crate::{empty_into_err::EmptyIntoErr, empty_into_none::EmptyIntoNone, is_empty::IsEmpty};
+
+/// Extractor that rejects the request if the value extracted by `E` is empty.
+///
+/// The rejection is [`NonEmptyRejection`], which responds with
+/// `422 Unprocessable Entity` for empty values, and with `E`'s own rejection
+/// otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use axum::Json;
+/// use optempty::axum::NonEmpty;
+///
+/// async fn tag(NonEmpty(Json(tags)): NonEmpty<Json<Vec<String>>>) {
+///     // `tags` has at least one element.
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonEmpty<E>(pub E);
+
+impl<S, E> FromRequestParts<S> for NonEmpty<E>
+where
+    E: FromRequestParts<S> + IsEmpty,
+    S: Send + Sync,
+{
+    type Rejection = NonEmptyRejection<E::Rejection>;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        E::from_request_parts(parts, state)
+            .await
+            .map_err(NonEmptyRejection::Inner)
+            .empty_into_err(|| NonEmptyRejection::Empty(EmptyRejection { body: false }))
+            .map(NonEmpty)
+    }
+}
+
+impl<S, E> FromRequest<S> for NonEmpty<E>
+where
+    E: FromRequest<S> + IsEmpty,
+    S: Send + Sync,
+{
+    type Rejection = NonEmptyRejection<E::Rejection>;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        // `Form` reads the query string of `GET` and `HEAD` requests, rather
+        // than the body. Without a body, that's the only place the value could
+        // have come from.
+        let body = !matches!(*req.method(), Method::GET | Method::HEAD)
+            || req.body().size_hint().exact() != Some(0);
+
+        E::from_request(req, state)
+            .await
+            .map_err(NonEmptyRejection::Inner)
+            .empty_into_err(|| NonEmptyRejection::Empty(EmptyRejection { body }))
+            .map(NonEmpty)
+    }
+}
+
+/// Extractor that gives `None` if the value extracted by `E` is empty.
+///
+/// `E` still has to succeed. For [`Query<T>`], that means `T` must be able to
+/// deserialize an empty query string, e.g., a map, or a struct where every
+/// field is an `Option` or has `#[serde(default)]`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use axum::extract::Query;
+/// use optempty::axum::EmptyAsNone;
+///
+/// async fn list(EmptyAsNone(filter): EmptyAsNone<Query<HashMap<String, String>>>) {
+///     // `filter` is `None` for both `/list` and `/list?`.
+/// }
+/// ```
+///
+/// [`Query<T>`]: ::axum::extract::Query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyAsNone<E>(pub Option<E>);
+
+impl<S, E> FromRequestParts<S> for EmptyAsNone<E>
+where
+    E: FromRequestParts<S> + IsEmpty,
+    S: Send + Sync,
+{
+    type Rejection = E::Rejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let value = E::from_request_parts(parts, state).await?;
+        Ok(EmptyAsNone(Some(value).empty_into_none()))
+    }
+}
+
+impl<S, E> FromRequest<S> for EmptyAsNone<E>
+where
+    E: FromRequest<S> + IsEmpty,
+    S: Send + Sync,
+{
+    type Rejection = E::Rejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let value = E::from_request(req, state).await?;
+        Ok(EmptyAsNone(Some(value).empty_into_none()))
+    }
+}
+
+/// Rejection used by [`NonEmpty`].
+#[derive(Debug)]
+pub enum NonEmptyRejection<R> {
+    /// The inner extractor rejected the request.
+    Inner(R),
+    /// The inner extractor succeeded, but the value was empty.
+    Empty(EmptyRejection),
+}
+
+impl<R> IntoResponse for NonEmptyRejection<R>
+where
+    R: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        match self {
+            NonEmptyRejection::Inner(inner) => inner.into_response(),
+            NonEmptyRejection::Empty(empty) => empty.into_response(),
+        }
+    }
+}
+
+impl<R> fmt::Display for NonEmptyRejection<R>
+where
+    R: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NonEmptyRejection::Inner(inner) => inner.fmt(f),
+            NonEmptyRejection::Empty(empty) => empty.fmt(f),
+        }
+    }
+}
+
+impl<R> Error for NonEmptyRejection<R>
+where
+    R: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NonEmptyRejection::Inner(inner) => Some(inner),
+            NonEmptyRejection::Empty(empty) => Some(empty),
+        }
+    }
+}
+
+/// The request had an empty body or empty parameters. Responds with
+/// `422 Unprocessable Entity`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyRejection {
+    body: bool,
+}
+
+impl EmptyRejection {
+    /// The status code of the response, `422 Unprocessable Entity`.
+    pub fn status(&self) -> StatusCode {
+        StatusCode::UNPROCESSABLE_ENTITY
+    }
+
+    /// The text of the response body.
+    pub fn body_text(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for EmptyRejection {
+    fn into_response(self) -> Response {
+        (self.status(), self.body_text()).into_response()
+    }
+}
+
+impl fmt::Display for EmptyRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.body {
+            f.write_str("Request body must not be empty")
+        } else {
+            f.write_str("Request parameters must not be empty")
+        }
+    }
+}
+
+impl Error for EmptyRejection {}
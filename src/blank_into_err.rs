@@ -0,0 +1,75 @@
+use super::is_blank::{IsBlank, Whitespace};
+
+/// Import this trait to add the `blank_into_err(op)` method to `Result<T, E>`,
+/// where `T` implements [`IsBlank`].
+///
+/// It's like [`EmptyIntoErr`], but also turns strings that are only
+/// whitespace into `Err`.
+///
+/// [`IsBlank`]: crate::is_blank::IsBlank
+/// [`EmptyIntoErr`]: crate::empty_into_err::EmptyIntoErr
+pub trait BlankIntoErr<T, E> {
+    /// If the value is `Result::Ok` with a blank value, calls `op` and returns
+    /// its return value in `Result::Err`. Otherwise the original value is
+    /// returned.
+    ///
+    /// Whitespace is [`Whitespace::Unicode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use optempty::BlankIntoErr;
+    /// #
+    /// let ok: Result<&str, &str> = Ok("  ");
+    /// assert_eq!(Err("was blank"), ok.blank_into_err(|| "was blank"));
+    ///
+    /// let ok: Result<&str, &str> = Ok(" a ");
+    /// assert_eq!(Ok(" a "), ok.blank_into_err(|| "was blank"));
+    ///
+    /// let err: Result<&str, &str> = Err("failed");
+    /// assert_eq!(Err("failed"), err.blank_into_err(|| "was blank"));
+    /// ```
+    ///
+    /// [`Whitespace::Unicode`]: crate::is_blank::Whitespace::Unicode
+    fn blank_into_err<O>(self, op: O) -> Result<T, E>
+    where
+        O: FnOnce() -> E;
+
+    /// Like [`blank_into_err`](Self::blank_into_err), but with the given
+    /// definition of whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use optempty::{is_blank::Whitespace, BlankIntoErr};
+    /// #
+    /// let ok: Result<&str, &str> = Ok("\u{A0}");
+    /// assert_eq!(Ok("\u{A0}"), ok.blank_into_err_with(Whitespace::Ascii, || "was blank"));
+    /// ```
+    fn blank_into_err_with<O>(self, whitespace: Whitespace, op: O) -> Result<T, E>
+    where
+        O: FnOnce() -> E;
+}
+
+impl<T, E> BlankIntoErr<T, E> for Result<T, E>
+where
+    T: IsBlank,
+{
+    fn blank_into_err<O>(self, op: O) -> Result<T, E>
+    where
+        O: FnOnce() -> E,
+    {
+        self.blank_into_err_with(Whitespace::Unicode, op)
+    }
+
+    fn blank_into_err_with<O>(self, whitespace: Whitespace, op: O) -> Result<T, E>
+    where
+        O: FnOnce() -> E,
+    {
+        if self.is_blank_with(whitespace) {
+            Err(op())
+        } else {
+            self
+        }
+    }
+}
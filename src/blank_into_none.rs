@@ -0,0 +1,56 @@
+use super::is_blank::{IsBlank, Whitespace};
+
+/// Import this trait to add the `blank_into_none()` method to `Option<T>`,
+/// where `T` implements [`IsBlank`].
+///
+/// It's like [`EmptyIntoNone`], but also turns strings that are only
+/// whitespace into `None`.
+///
+/// [`IsBlank`]: crate::is_blank::IsBlank
+/// [`EmptyIntoNone`]: crate::empty_into_none::EmptyIntoNone
+pub trait BlankIntoNone {
+    /// If the value is `Option::Some` with a blank value, returns
+    /// `Option::None`. Otherwise, the original value is returned.
+    ///
+    /// Whitespace is [`Whitespace::Unicode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use optempty::BlankIntoNone;
+    /// #
+    /// assert_eq!(None, Some(" \t").blank_into_none());
+    /// assert_eq!(None, Some("").blank_into_none());
+    /// assert_eq!(Some(" a "), Some(" a ").blank_into_none());
+    /// ```
+    ///
+    /// [`Whitespace::Unicode`]: crate::is_blank::Whitespace::Unicode
+    fn blank_into_none(self) -> Self;
+
+    /// Like [`blank_into_none`](Self::blank_into_none), but with the given
+    /// definition of whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use optempty::{is_blank::Whitespace, BlankIntoNone};
+    /// #
+    /// let some = Some(String::from("\u{FEFF}"));
+    /// assert_eq!(some, some.clone().blank_into_none());
+    /// assert_eq!(None, some.blank_into_none_with(Whitespace::UnicodeAndZeroWidth));
+    /// ```
+    fn blank_into_none_with(self, whitespace: Whitespace) -> Self;
+}
+
+impl<T> BlankIntoNone for Option<T>
+where
+    T: IsBlank,
+{
+    fn blank_into_none(self) -> Self {
+        self.blank_into_none_with(Whitespace::Unicode)
+    }
+
+    fn blank_into_none_with(self, whitespace: Whitespace) -> Self {
+        self.filter(|value| !value.is_blank_with(whitespace))
+    }
+}
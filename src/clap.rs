@@ -0,0 +1,256 @@
+//! [clap] value parsers that reject, or skip, empty arguments, like
+//! `--name ""`.
+//!
+//! Each one wraps another [`TypedValueParser`], such as
+//! [`StringValueParser`], [`OsStringValueParser`], or [`PathBufValueParser`],
+//! and only checks the raw argument before handing it on.
+//!
+//! * [`NonEmpty`] rejects empty arguments.
+//! * [`NonBlank`] rejects empty arguments, and ones that are only whitespace.
+//! * [`EmptyAsNone`] parses empty arguments as `None`.
+//!
+//! # Examples
+//!
+//! ```
+//! use clap::{builder::PathBufValueParser, error::ErrorKind, Arg, Command};
+//! use optempty::clap::NonEmpty;
+//!
+//! let cmd = Command::new("app").arg(
+//!     Arg::new("config")
+//!         .long("config")
+//!         .value_parser(NonEmpty::new(PathBufValueParser::new())),
+//! );
+//!
+//! let err = cmd
+//!     .try_get_matches_from(["app", "--config", ""])
+//!     .unwrap_err();
+//! assert_eq!(ErrorKind::InvalidValue, err.kind());
+//! ```
+//!
+//! [clap]: ::clap
+//! [`TypedValueParser`]: ::clap::builder::TypedValueParser
+//! [`StringValueParser`]: ::clap::builder::StringValueParser
+//! [`OsStringValueParser`]: ::clap::builder::OsStringValueParser
+//! [`PathBufValueParser`]: ::clap::builder::PathBufValueParser
+
+use std::ffi::OsStr;
+
+use clap::{
+    builder::{PossibleValue, TypedValueParser},
+    error::{ContextKind, ContextValue, ErrorKind},
+    Arg, Command, Error,
+};
+
+use crate::{empty_into_none::EmptyIntoNone, is_blank::IsBlank};
+
+/// Rejects empty arguments, otherwise parses them with `P`.
+///
+/// # Examples
+///
+/// ```
+/// use clap::{builder::StringValueParser, Arg, Command};
+/// use optempty::clap::NonEmpty;
+///
+/// let cmd = Command::new("app").arg(
+///     Arg::new("name")
+///         .long("name")
+///         .value_parser(NonEmpty::new(StringValueParser::new())),
+/// );
+///
+/// let matches = cmd
+///     .clone()
+///     .try_get_matches_from(["app", "--name", "Zoe"])
+///     .unwrap();
+/// assert_eq!(Some("Zoe"), matches.get_one::<String>("name").map(String::as_str));
+///
+/// assert!(cmd.try_get_matches_from(["app", "--name", ""]).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NonEmpty<P> {
+    inner: P,
+}
+
+impl<P> NonEmpty<P> {
+    /// Checks arguments before they're parsed by `inner`.
+    pub fn new(inner: P) -> Self {
+        Self { inner }
+    }
+}
+
+impl<P> TypedValueParser for NonEmpty<P>
+where
+    P: TypedValueParser,
+{
+    type Value = P::Value;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        if value.is_empty() {
+            return Err(invalid_value(cmd, arg, value, None));
+        }
+
+        self.inner.parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        self.inner.possible_values()
+    }
+}
+
+/// Rejects arguments that are empty or only whitespace, otherwise parses them
+/// with `P`.
+///
+/// The argument is passed to `P` as it is, without being trimmed.
+///
+/// # Examples
+///
+/// ```
+/// use clap::{builder::StringValueParser, Arg, Command};
+/// use optempty::clap::NonBlank;
+///
+/// let cmd = Command::new("app").arg(
+///     Arg::new("name")
+///         .long("name")
+///         .value_parser(NonBlank::new(StringValueParser::new())),
+/// );
+///
+/// assert!(cmd.clone().try_get_matches_from(["app", "--name", " Zoe "]).is_ok());
+/// assert!(cmd.clone().try_get_matches_from(["app", "--name", " \t"]).is_err());
+/// assert!(cmd.try_get_matches_from(["app", "--name", ""]).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NonBlank<P> {
+    inner: P,
+}
+
+impl<P> NonBlank<P> {
+    /// Checks arguments before they're parsed by `inner`.
+    pub fn new(inner: P) -> Self {
+        Self { inner }
+    }
+}
+
+impl<P> TypedValueParser for NonBlank<P>
+where
+    P: TypedValueParser,
+{
+    type Value = P::Value;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        if value.is_blank() {
+            return Err(invalid_value(
+                cmd,
+                arg,
+                value,
+                Some("the value must not be blank"),
+            ));
+        }
+
+        self.inner.parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        self.inner.possible_values()
+    }
+}
+
+/// Parses empty arguments as `None`, and others as `Some` of what `P` parses.
+///
+/// The argument's value type is `Option<P::Value>`, so get it with, e.g.,
+/// `get_one::<Option<String>>`.
+///
+/// # Examples
+///
+/// ```
+/// use clap::{builder::StringValueParser, Arg, ArgMatches, Command};
+/// use optempty::clap::EmptyAsNone;
+///
+/// let cmd = Command::new("app").arg(
+///     Arg::new("name")
+///         .long("name")
+///         .value_parser(EmptyAsNone::new(StringValueParser::new())),
+/// );
+///
+/// fn name(matches: &ArgMatches) -> Option<&str> {
+///     matches.get_one::<Option<String>>("name")?.as_deref()
+/// }
+///
+/// let matches = cmd.clone().try_get_matches_from(["app", "--name", ""]).unwrap();
+/// assert_eq!(None, name(&matches));
+///
+/// let matches = cmd.try_get_matches_from(["app", "--name", "Zoe"]).unwrap();
+/// assert_eq!(Some("Zoe"), name(&matches));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EmptyAsNone<P> {
+    inner: P,
+}
+
+impl<P> EmptyAsNone<P> {
+    /// Parses non-empty arguments with `inner`.
+    pub fn new(inner: P) -> Self {
+        Self { inner }
+    }
+}
+
+impl<P> TypedValueParser for EmptyAsNone<P>
+where
+    P: TypedValueParser,
+{
+    type Value = Option<P::Value>;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        Some(value)
+            .empty_into_none()
+            .map(|value| self.inner.parse_ref(cmd, arg, value))
+            .transpose()
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        self.inner.possible_values()
+    }
+}
+
+/// The same error clap gives for a value that isn't allowed, with an optional
+/// tip saying why.
+fn invalid_value(
+    cmd: &Command,
+    arg: Option<&Arg>,
+    value: &OsStr,
+    tip: Option<&'static str>,
+) -> Error {
+    let mut err = Error::new(ErrorKind::InvalidValue).with_cmd(cmd);
+
+    let arg = arg.map_or_else(|| "...".to_owned(), ToString::to_string);
+    err.insert(ContextKind::InvalidArg, ContextValue::String(arg));
+    err.insert(
+        ContextKind::InvalidValue,
+        ContextValue::String(value.to_string_lossy().into_owned()),
+    );
+    if let Some(tip) = tip {
+        err.insert(
+            ContextKind::Suggested,
+            ContextValue::StyledStrs(vec![tip.into()]),
+        );
+    }
+    err.insert(
+        ContextKind::Usage,
+        ContextValue::StyledStr(cmd.clone().render_usage()),
+    );
+
+    err
+}
@@ -0,0 +1,29 @@
+extern crate alloc;
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+    ffi::CString,
+    string::String,
+    vec::Vec,
+};
+
+use super::Empty;
+
+// Implement `Empty` for the collections in `alloc`.
+
+crate::impl_empty!(BinaryHeap; T where T: Ord);
+crate::impl_empty!(BTreeMap; K, V);
+crate::impl_empty!(BTreeSet; T);
+crate::impl_empty!(LinkedList; T);
+crate::impl_empty!(Vec; T);
+crate::impl_empty!(VecDeque; T);
+
+// Some other things from `alloc`.
+
+crate::impl_empty!(String);
+
+impl Empty for CString {
+    fn empty() -> Self {
+        CString::default()
+    }
+}
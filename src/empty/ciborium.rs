@@ -0,0 +1,10 @@
+use ciborium::Value;
+
+use super::Empty;
+
+impl Empty for Value {
+    /// Returns `Value::Null`.
+    fn empty() -> Self {
+        Value::Null
+    }
+}
@@ -0,0 +1,41 @@
+use core::ffi::CStr;
+
+use super::Empty;
+
+impl Empty for &str {
+    fn empty() -> Self {
+        ""
+    }
+}
+
+impl<T> Empty for &[T] {
+    fn empty() -> Self {
+        &[]
+    }
+}
+
+impl Empty for &CStr {
+    fn empty() -> Self {
+        Default::default()
+    }
+}
+
+impl<T> Empty for Option<T>
+where
+    T: Empty,
+{
+    /// Returns `None`.
+    fn empty() -> Self {
+        None
+    }
+}
+
+impl<T, E> Empty for Result<T, E>
+where
+    T: Empty,
+{
+    /// Returns `Ok` with an empty `T`.
+    fn empty() -> Self {
+        Ok(T::empty())
+    }
+}
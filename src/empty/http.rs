@@ -0,0 +1,21 @@
+use http::{Extensions, HeaderMap, HeaderValue};
+
+use super::Empty;
+
+impl Empty for HeaderMap {
+    fn empty() -> Self {
+        HeaderMap::new()
+    }
+}
+
+impl Empty for HeaderValue {
+    fn empty() -> Self {
+        HeaderValue::from_static("")
+    }
+}
+
+impl Empty for Extensions {
+    fn empty() -> Self {
+        Extensions::new()
+    }
+}
@@ -0,0 +1,153 @@
+//! The [`Empty`] trait, for making empty values.
+//!
+//! [`Empty`]: crate::empty::Empty
+
+#[cfg(feature = "alloc")]
+mod alloc;
+#[cfg(feature = "ciborium")]
+mod ciborium;
+mod core;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(all(feature = "querymap", feature = "std"))]
+mod query_map;
+#[cfg(feature = "serdejson")]
+mod serde_json;
+#[cfg(feature = "serde_yaml")]
+mod serde_yaml;
+#[cfg(feature = "std")]
+mod std;
+#[cfg(feature = "toml")]
+mod toml;
+
+/// Used to make an empty collection, or other empty thing (like a `String`).
+///
+/// `Empty` is implemented for many of the same types as [`IsEmpty`], and the
+/// value returned by [`Empty::empty`] is always [`IsEmpty::is_empty`].
+/// `Option<T>` and `Result<T, E>` implement it when `T` does.
+///
+/// # Examples
+///
+/// ```
+/// use optempty::{Empty, IsEmpty};
+///
+/// let v: Vec<&str> = Empty::empty();
+/// assert!(v.is_empty());
+///
+/// let s = <&str>::empty();
+/// assert_eq!("", s);
+///
+/// let none = Option::<String>::empty();
+/// assert_eq!(None, none);
+///
+/// let ok = Result::<String, &str>::empty();
+/// assert_eq!(Ok(String::new()), ok);
+/// ```
+///
+/// [`IsEmpty`]: crate::is_empty::IsEmpty
+/// [`IsEmpty::is_empty`]: crate::is_empty::IsEmpty::is_empty
+pub trait Empty {
+    /// Returns an empty value.
+    fn empty() -> Self;
+}
+
+/// Implements [`Empty`] for a type that already has a `new() -> Self`
+/// function making an empty value, by calling that function.
+///
+/// It takes the same forms as [`impl_is_empty!`].
+///
+/// # Examples
+///
+/// ```
+/// # use optempty::{Empty, IsEmpty};
+/// #
+/// struct Names(Vec<String>);
+///
+/// impl Names {
+///     fn new() -> Self {
+///         Names(Vec::new())
+///     }
+///
+///     fn is_empty(&self) -> bool {
+///         self.0.is_empty()
+///     }
+/// }
+///
+/// optempty::impl_empty!(Names);
+/// optempty::impl_is_empty!(Names);
+///
+/// assert!(IsEmpty::is_empty(&Names::empty()));
+/// ```
+///
+/// A type with generic type parameters lists them after a `;`, optionally
+/// followed by a `where` clause:
+/// ```
+/// # use optempty::Empty;
+/// #
+/// struct Bag<T, U>(Vec<(T, U)>);
+///
+/// impl<T, U> Bag<T, U>
+/// where
+///     T: Clone,
+/// {
+///     fn new() -> Self {
+///         Bag(Vec::new())
+///     }
+/// }
+///
+/// optempty::impl_empty!(Bag; T, U where T: Clone);
+///
+/// assert!(Bag::<u8, u8>::empty().0.is_empty());
+/// ```
+///
+/// Anything else, such as lifetimes or const generics, can be written out in
+/// full with the generic parameters in `[]`, followed by the type, and an
+/// optional `where` clause:
+/// ```
+/// # use optempty::Empty;
+/// #
+/// struct Buffer<T, const N: usize> {
+///     items: [Option<T>; N],
+///     len: usize,
+/// }
+///
+/// impl<T: Copy, const N: usize> Buffer<T, N> {
+///     fn new() -> Self {
+///         Buffer {
+///             items: [None; N],
+///             len: 0,
+///         }
+///     }
+/// }
+///
+/// optempty::impl_empty!([T, const N: usize] Buffer<T, N> where T: Copy);
+///
+/// assert_eq!(0, Buffer::<u8, 4>::empty().len);
+/// ```
+///
+/// [`Empty`]: crate::empty::Empty
+/// [`impl_is_empty!`]: crate::impl_is_empty
+#[macro_export]
+macro_rules! impl_empty {
+    ([$($generics:tt)*] $type:ty $(where $($bounds:tt)+)?) => {
+        impl<$($generics)*> $crate::empty::Empty for $type $(where $($bounds)+)? {
+            fn empty() -> Self {
+                <$type>::new()
+            }
+        }
+    };
+    ($type:ty) => {
+        impl $crate::empty::Empty for $type {
+            fn empty() -> Self {
+                <$type>::new()
+            }
+        }
+    };
+    ($type:ident; $($args:ident),+ $(where $($bounds:tt)+)?) => {
+        impl<$($args),+> $crate::empty::Empty for $type<$($args),+> $(where $($bounds)+)? {
+            fn empty() -> Self {
+                $type::new()
+            }
+        }
+    };
+}
@@ -0,0 +1,9 @@
+use query_map::QueryMap;
+
+use super::Empty;
+
+impl Empty for QueryMap {
+    fn empty() -> Self {
+        QueryMap::default()
+    }
+}
@@ -0,0 +1,19 @@
+extern crate alloc;
+use alloc::string::String;
+
+use serde_json::{Map, Value};
+
+use super::Empty;
+
+impl Empty for Map<String, Value> {
+    fn empty() -> Self {
+        Map::new()
+    }
+}
+
+impl Empty for Value {
+    /// Returns `Value::Null`.
+    fn empty() -> Self {
+        Value::Null
+    }
+}
@@ -0,0 +1,16 @@
+use serde_yaml::{Mapping, Value};
+
+use super::Empty;
+
+impl Empty for Mapping {
+    fn empty() -> Self {
+        Mapping::new()
+    }
+}
+
+impl Empty for Value {
+    /// Returns `Value::Null`.
+    fn empty() -> Self {
+        Value::Null
+    }
+}
@@ -0,0 +1,46 @@
+extern crate std;
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
+};
+
+use super::Empty;
+
+// Implement `Empty` for the std collections.
+
+impl<K, V, S> Empty for HashMap<K, V, S>
+where
+    S: Default,
+{
+    fn empty() -> Self {
+        HashMap::default()
+    }
+}
+
+impl<T, S> Empty for HashSet<T, S>
+where
+    S: Default,
+{
+    fn empty() -> Self {
+        HashSet::default()
+    }
+}
+
+// Some other things from the stdlib.
+
+impl Empty for &OsStr {
+    fn empty() -> Self {
+        OsStr::new("")
+    }
+}
+
+crate::impl_empty!(OsString);
+
+impl Empty for &Path {
+    fn empty() -> Self {
+        Path::new("")
+    }
+}
+
+crate::impl_empty!(PathBuf);
@@ -0,0 +1,9 @@
+use toml::Table;
+
+use super::Empty;
+
+impl Empty for Table {
+    fn empty() -> Self {
+        Table::new()
+    }
+}
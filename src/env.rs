@@ -0,0 +1,170 @@
+//! Reading environment variables, treating empty ones as unset.
+//!
+//! Running `FOO= ./app` is a common way to unset `FOO`, but [`std::env::var`]
+//! still returns `Ok("")` for it.
+//!
+//! # Examples
+//!
+//! ```
+//! use optempty::env;
+//!
+//! std::env::set_var("OPTEMPTY_DOC_LOG_LEVEL", "");
+//! assert_eq!(Ok(String::new()), std::env::var("OPTEMPTY_DOC_LOG_LEVEL"));
+//!
+//! let level = env::var_non_empty("OPTEMPTY_DOC_LOG_LEVEL").unwrap_or_else(|| "info".into());
+//! assert_eq!("info", level);
+//! ```
+
+use std::{
+    env,
+    error::Error,
+    ffi::{OsStr, OsString},
+    fmt,
+    str::FromStr,
+};
+
+use crate::empty_into_none::EmptyIntoNone;
+
+/// Returns the value of the environment variable `key`, or `None` if it isn't
+/// set, is empty, or isn't valid unicode.
+///
+/// # Examples
+///
+/// ```
+/// use optempty::env::var_non_empty;
+///
+/// std::env::set_var("OPTEMPTY_DOC_VAR_A", "");
+/// assert_eq!(None, var_non_empty("OPTEMPTY_DOC_VAR_A"));
+///
+/// std::env::set_var("OPTEMPTY_DOC_VAR_A", "a");
+/// assert_eq!(Some("a".to_owned()), var_non_empty("OPTEMPTY_DOC_VAR_A"));
+/// ```
+pub fn var_non_empty<K>(key: K) -> Option<String>
+where
+    K: AsRef<OsStr>,
+{
+    env::var(key).ok().empty_into_none()
+}
+
+/// Returns the value of the environment variable `key`, or `None` if it isn't
+/// set or is empty.
+///
+/// # Examples
+///
+/// ```
+/// use optempty::env::var_os_non_empty;
+///
+/// std::env::set_var("OPTEMPTY_DOC_VAR_OS", "");
+/// assert_eq!(None, var_os_non_empty("OPTEMPTY_DOC_VAR_OS"));
+/// ```
+pub fn var_os_non_empty<K>(key: K) -> Option<OsString>
+where
+    K: AsRef<OsStr>,
+{
+    env::var_os(key).empty_into_none()
+}
+
+/// Parses the value of the environment variable `key`, or returns `Ok(None)`
+/// if it isn't set or is empty.
+///
+/// # Errors
+///
+/// If the value isn't valid unicode, or `T` fails to parse it.
+///
+/// # Examples
+///
+/// ```
+/// use optempty::env::{parse_non_empty, ParseVarError};
+///
+/// std::env::set_var("OPTEMPTY_DOC_PORT", "");
+/// assert_eq!(Ok(None), parse_non_empty::<u16>("OPTEMPTY_DOC_PORT"));
+///
+/// std::env::set_var("OPTEMPTY_DOC_PORT", "8080");
+/// assert_eq!(Ok(Some(8080)), parse_non_empty::<u16>("OPTEMPTY_DOC_PORT"));
+///
+/// std::env::set_var("OPTEMPTY_DOC_PORT", "http");
+/// assert!(matches!(
+///     parse_non_empty::<u16>("OPTEMPTY_DOC_PORT"),
+///     Err(ParseVarError::Parse(_)),
+/// ));
+/// ```
+pub fn parse_non_empty<T>(key: impl AsRef<OsStr>) -> Result<Option<T>, ParseVarError<T::Err>>
+where
+    T: FromStr,
+{
+    var_os_non_empty(key)
+        .map(|value| {
+            value
+                .into_string()
+                .map_err(ParseVarError::NotUnicode)?
+                .parse()
+                .map_err(ParseVarError::Parse)
+        })
+        .transpose()
+}
+
+/// Returns an iterator over the environment variables whose names start with
+/// `prefix`, and whose values aren't empty.
+///
+/// Unlike [`std::env::vars`], variables whose name or value isn't valid
+/// unicode are skipped rather than causing a panic.
+///
+/// # Examples
+///
+/// ```
+/// use optempty::env::vars_non_empty;
+///
+/// std::env::set_var("OPTEMPTY_DOC_VARS_A", "a");
+/// std::env::set_var("OPTEMPTY_DOC_VARS_B", "");
+///
+/// let vars: Vec<_> = vars_non_empty("OPTEMPTY_DOC_VARS_").collect();
+/// assert_eq!(vec![("OPTEMPTY_DOC_VARS_A".to_owned(), "a".to_owned())], vars);
+/// ```
+pub fn vars_non_empty(prefix: &str) -> impl Iterator<Item = (String, String)> + '_ {
+    env::vars_os().filter_map(move |(key, value)| {
+        let key = key.into_string().ok()?;
+        if !key.starts_with(prefix) {
+            return None;
+        }
+
+        let value = value.into_string().ok().empty_into_none()?;
+        Some((key, value))
+    })
+}
+
+/// Returned by [`parse_non_empty`] when a variable is set but can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseVarError<E> {
+    /// The value wasn't valid unicode.
+    NotUnicode(OsString),
+    /// The value couldn't be parsed.
+    Parse(E),
+}
+
+impl<E> fmt::Display for ParseVarError<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseVarError::NotUnicode(value) => {
+                write!(f, "environment variable was not valid unicode: {value:?}")
+            }
+            ParseVarError::Parse(err) => {
+                write!(f, "environment variable could not be parsed: {err}")
+            }
+        }
+    }
+}
+
+impl<E> Error for ParseVarError<E>
+where
+    E: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseVarError::NotUnicode(_) => None,
+            ParseVarError::Parse(err) => Some(err),
+        }
+    }
+}
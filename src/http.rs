@@ -0,0 +1,53 @@
+//! Treating empty header values, like `Accept-Encoding:` with nothing after
+//! it, as missing.
+//!
+//! # Examples
+//!
+//! ```
+//! use http::{header::ACCEPT_ENCODING, HeaderMap, HeaderValue};
+//! use optempty::http::HeaderMapNonEmpty;
+//!
+//! let mut headers = HeaderMap::new();
+//! headers.insert(ACCEPT_ENCODING, HeaderValue::from_static(""));
+//!
+//! assert!(headers.get(ACCEPT_ENCODING).is_some());
+//! assert_eq!(None, headers.get_non_empty(ACCEPT_ENCODING));
+//! ```
+
+use http::{header::AsHeaderName, HeaderMap, HeaderValue};
+
+/// Import this trait to add methods to [`HeaderMap`] that skip empty values.
+///
+/// [`HeaderMap`]: ::http::HeaderMap
+pub trait HeaderMapNonEmpty {
+    /// Returns the first non-empty value for `key`, or `None` if there isn't
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http::{header::ACCEPT, HeaderMap, HeaderValue};
+    /// use optempty::http::HeaderMapNonEmpty;
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.append(ACCEPT, HeaderValue::from_static(""));
+    /// headers.append(ACCEPT, HeaderValue::from_static("text/html"));
+    /// headers.insert("x-empty", HeaderValue::from_static(""));
+    ///
+    /// assert_eq!(Some(&HeaderValue::from_static("text/html")), headers.get_non_empty(ACCEPT));
+    /// assert_eq!(None, headers.get_non_empty("x-empty"));
+    /// assert_eq!(None, headers.get_non_empty("x-missing"));
+    /// ```
+    fn get_non_empty<K>(&self, key: K) -> Option<&HeaderValue>
+    where
+        K: AsHeaderName;
+}
+
+impl HeaderMapNonEmpty for HeaderMap {
+    fn get_non_empty<K>(&self, key: K) -> Option<&HeaderValue>
+    where
+        K: AsHeaderName,
+    {
+        self.get_all(key).iter().find(|value| !value.is_empty())
+    }
+}
@@ -0,0 +1,180 @@
+//! Treating empty request and response bodies as missing, without buffering
+//! them.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! use bytes::Bytes;
+//! use http_body_util::{BodyExt, Empty, Full};
+//! use optempty::http_body::BodyEmptyIntoNone;
+//!
+//! assert!(Empty::<Bytes>::new().empty_into_none().await.is_none());
+//!
+//! let body = Full::new(Bytes::from("hello"))
+//!     .empty_into_none()
+//!     .await
+//!     .unwrap();
+//! assert_eq!("hello", body.collect().await.unwrap().to_bytes());
+//! # }
+//! ```
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use bytes::Buf;
+use http_body::{Body, Frame, SizeHint};
+
+/// Import this trait to add [`empty_into_none`] to any [`Body`].
+///
+/// A body is empty if it has no data. That's known without reading anything
+/// if [`Body::is_end_stream`] returns `true`, or [`Body::size_hint`] is
+/// exactly `0`. Otherwise, the body is polled until it produces data that
+/// isn't empty, or an error, or ends. That frame is handed back first by the
+/// returned [`PeekedBody`], so no data is lost. Only that one frame is held;
+/// the rest of the body isn't read.
+///
+/// Trailers aren't data, so a body with only trailers is empty, and they're
+/// dropped along with it.
+///
+/// A body that isn't [`Unpin`] can be used with [`Box::pin`].
+///
+/// [`empty_into_none`]: BodyEmptyIntoNone::empty_into_none
+/// [`Body`]: http_body::Body
+/// [`Body::is_end_stream`]: http_body::Body::is_end_stream
+/// [`Body::size_hint`]: http_body::Body::size_hint
+pub trait BodyEmptyIntoNone: Body + Sized {
+    /// Resolves to `None` if the body is empty, otherwise `Some` of the body.
+    ///
+    /// An error while reading the first frame doesn't make the body empty. It
+    /// is returned from the first poll of the body.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use bytes::Bytes;
+    /// use http_body_util::{BodyExt, Full};
+    /// use optempty::http_body::BodyEmptyIntoNone;
+    ///
+    /// assert!(Full::new(Bytes::new()).empty_into_none().await.is_none());
+    ///
+    /// let body = Full::new(Bytes::from("{}")).empty_into_none().await;
+    /// let bytes = body.unwrap().collect().await.unwrap().to_bytes();
+    /// assert_eq!("{}", bytes);
+    /// # }
+    /// ```
+    fn empty_into_none(self) -> EmptyIntoNoneFuture<Self>;
+}
+
+impl<B> BodyEmptyIntoNone for B
+where
+    B: Body + Unpin,
+{
+    fn empty_into_none(self) -> EmptyIntoNoneFuture<Self> {
+        EmptyIntoNoneFuture { body: Some(self) }
+    }
+}
+
+/// The future returned by [`BodyEmptyIntoNone::empty_into_none`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct EmptyIntoNoneFuture<B> {
+    body: Option<B>,
+}
+
+impl<B> Future for EmptyIntoNoneFuture<B>
+where
+    B: Body + Unpin,
+{
+    type Output = Option<PeekedBody<B>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let body = self
+            .body
+            .as_mut()
+            .expect("`EmptyIntoNoneFuture` polled after completion");
+
+        let first = loop {
+            if body.is_end_stream() || body.size_hint().exact() == Some(0) {
+                break None;
+            }
+
+            match ready!(Pin::new(&mut *body).poll_frame(cx)) {
+                // Neither empty data nor trailers make the body non-empty.
+                Some(Ok(frame)) if !frame.data_ref().is_some_and(Buf::has_remaining) => {}
+                first => break first,
+            }
+        };
+
+        let body = self.body.take();
+        Poll::Ready(first.zip(body).map(|(first, body)| PeekedBody {
+            first: Some(first),
+            body,
+        }))
+    }
+}
+
+/// A non-empty [`Body`], returned by [`BodyEmptyIntoNone::empty_into_none`].
+///
+/// Yields the frame that was read to find out the body isn't empty, then the
+/// rest of the body.
+///
+/// [`Body`]: http_body::Body
+pub struct PeekedBody<B>
+where
+    B: Body,
+{
+    first: Option<Result<Frame<B::Data>, B::Error>>,
+    body: B,
+}
+
+// `first` is never pinned, so only `body` needs to be `Unpin`.
+impl<B> Unpin for PeekedBody<B> where B: Body + Unpin {}
+
+impl<B> Body for PeekedBody<B>
+where
+    B: Body + Unpin,
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+        match this.first.take() {
+            Some(first) => Poll::Ready(Some(first)),
+            None => Pin::new(&mut this.body).poll_frame(cx),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.first.is_none() && self.body.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        let mut hint = self.body.size_hint();
+
+        if let Some(data) = self
+            .first
+            .as_ref()
+            .and_then(|first| first.as_ref().ok())
+            .and_then(Frame::data_ref)
+        {
+            let len = data.remaining() as u64;
+            // The upper bound first, so the lower bound is never above it.
+            if let Some(upper) = hint.upper() {
+                hint.set_upper(upper.saturating_add(len));
+            }
+            hint.set_lower(hint.lower().saturating_add(len));
+        }
+
+        hint
+    }
+}
@@ -0,0 +1,57 @@
+extern crate alloc;
+
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    rc::Rc,
+    string::String,
+};
+
+use super::{IsBlank, Whitespace};
+
+impl IsBlank for String {
+    fn is_blank_with(&self, whitespace: Whitespace) -> bool {
+        whitespace.is_blank(self)
+    }
+}
+
+// Smart pointers are blank when what they point to is blank.
+
+impl<T> IsBlank for Box<T>
+where
+    T: IsBlank + ?Sized,
+{
+    fn is_blank_with(&self, whitespace: Whitespace) -> bool {
+        IsBlank::is_blank_with(&**self, whitespace)
+    }
+}
+
+impl<T> IsBlank for Rc<T>
+where
+    T: IsBlank + ?Sized,
+{
+    fn is_blank_with(&self, whitespace: Whitespace) -> bool {
+        IsBlank::is_blank_with(&**self, whitespace)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> IsBlank for Arc<T>
+where
+    T: IsBlank + ?Sized,
+{
+    fn is_blank_with(&self, whitespace: Whitespace) -> bool {
+        IsBlank::is_blank_with(&**self, whitespace)
+    }
+}
+
+impl<B> IsBlank for Cow<'_, B>
+where
+    B: IsBlank + ToOwned + ?Sized,
+{
+    fn is_blank_with(&self, whitespace: Whitespace) -> bool {
+        IsBlank::is_blank_with(&**self, whitespace)
+    }
+}
@@ -0,0 +1,85 @@
+use core::{ops::Deref, pin::Pin};
+
+use super::{IsBlank, Whitespace};
+
+impl<T> IsBlank for &T
+where
+    T: IsBlank + ?Sized,
+{
+    fn is_blank_with(&self, whitespace: Whitespace) -> bool {
+        IsBlank::is_blank_with(*self, whitespace)
+    }
+}
+
+impl<T> IsBlank for &mut T
+where
+    T: IsBlank + ?Sized,
+{
+    fn is_blank_with(&self, whitespace: Whitespace) -> bool {
+        IsBlank::is_blank_with(*self, whitespace)
+    }
+}
+
+impl<P> IsBlank for Pin<P>
+where
+    P: Deref,
+    P::Target: IsBlank,
+{
+    fn is_blank_with(&self, whitespace: Whitespace) -> bool {
+        IsBlank::is_blank_with(&**self, whitespace)
+    }
+}
+
+impl IsBlank for str {
+    fn is_blank_with(&self, whitespace: Whitespace) -> bool {
+        whitespace.is_blank(self)
+    }
+}
+
+impl<T> IsBlank for Option<T>
+where
+    T: IsBlank,
+{
+    /// Returns `true` if `Option` is `None` or `Some` with a blank `IsBlank`,
+    /// otherwise, `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use optempty::IsBlank;
+    /// #
+    /// assert!(None::<&str>.is_blank());
+    /// assert!(Some(" ").is_blank());
+    /// assert!(!Some("a").is_blank());
+    /// ```
+    fn is_blank_with(&self, whitespace: Whitespace) -> bool {
+        self.as_ref()
+            .map(|value| value.is_blank_with(whitespace))
+            .unwrap_or(true)
+    }
+}
+
+impl<T, E> IsBlank for Result<T, E>
+where
+    T: IsBlank,
+{
+    /// Returns `true` if `Result` is `Ok` with a blank `IsBlank`, otherwise,
+    /// `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use optempty::IsBlank;
+    /// #
+    /// let ok: Result<&str, &str> = Ok(" ");
+    /// assert!(ok.is_blank());
+    ///
+    /// let err: Result<&str, &str> = Err(" ");
+    /// assert!(!err.is_blank());
+    /// ```
+    fn is_blank_with(&self, whitespace: Whitespace) -> bool {
+        self.as_ref()
+            .map(|value| value.is_blank_with(whitespace))
+            .unwrap_or(false)
+    }
+}
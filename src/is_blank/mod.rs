@@ -0,0 +1,115 @@
+//! The [`IsBlank`] trait, for strings that are empty or only whitespace.
+//!
+//! [`IsBlank`]: crate::is_blank::IsBlank
+
+#[cfg(feature = "alloc")]
+mod alloc;
+mod core;
+#[cfg(feature = "std")]
+mod std;
+
+/// Used to determine if a string is blank: empty, or only [`Whitespace`].
+///
+/// Where [`IsEmpty`] says `"  "` isn't empty, `IsBlank` says it's blank.
+///
+/// `IsBlank` is implemented for `str`, `String`, `OsStr`, and `OsString`, and
+/// for `Option<T>` and `Result<T, E>` where `T` implements `IsBlank`, in the
+/// same way as [`IsEmpty`]. It's also implemented for references and smart
+/// pointers (`Box<T>`, `Rc<T>`, `Arc<T>`, `Cow<'_, B>`, `Pin<P>`) to anything
+/// that implements `IsBlank`.
+///
+/// # Examples
+///
+/// ```
+/// use optempty::{is_blank::Whitespace, IsBlank, IsEmpty};
+///
+/// assert!(!IsEmpty::is_empty(" \t\n"));
+/// assert!(" \t\n".is_blank());
+/// assert!(!" a ".is_blank());
+///
+/// // A zero width space isn't Unicode `White_Space`.
+/// assert!(!"\u{200B}".is_blank());
+/// assert!("\u{200B}".is_blank_with(Whitespace::UnicodeAndZeroWidth));
+///
+/// assert!(None::<&str>.is_blank());
+/// assert!(Some(" ").is_blank());
+/// ```
+///
+/// [`IsEmpty`]: crate::is_empty::IsEmpty
+pub trait IsBlank {
+    /// Returns `true` if the value is empty or only [`Whitespace::Unicode`].
+    fn is_blank(&self) -> bool {
+        self.is_blank_with(Whitespace::Unicode)
+    }
+
+    /// Returns `true` if the value is empty or only `whitespace`.
+    fn is_blank_with(&self, whitespace: Whitespace) -> bool;
+}
+
+/// Which characters count as whitespace, for [`IsBlank`] and
+/// [`TrimIntoNone`].
+///
+/// [`TrimIntoNone`]: crate::trim_into_none::TrimIntoNone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Whitespace {
+    /// ASCII whitespace, as with [`char::is_ascii_whitespace`]: space, tab,
+    /// line feed, form feed, and carriage return.
+    Ascii,
+    /// Unicode `White_Space`, as with [`char::is_whitespace`] and
+    /// [`str::trim`].
+    #[default]
+    Unicode,
+    /// [`Whitespace::Unicode`], plus characters that aren't `White_Space` but
+    /// still can't be seen: zero width space (`U+200B`), zero width non-joiner
+    /// (`U+200C`), zero width joiner (`U+200D`), word joiner (`U+2060`), and
+    /// the byte order mark, or zero width no-break space (`U+FEFF`).
+    UnicodeAndZeroWidth,
+}
+
+impl Whitespace {
+    /// Returns `true` if `c` is whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optempty::is_blank::Whitespace;
+    ///
+    /// assert!(Whitespace::Ascii.is_whitespace('\t'));
+    /// assert!(!Whitespace::Ascii.is_whitespace('\u{A0}'));
+    /// assert!(Whitespace::Unicode.is_whitespace('\u{A0}'));
+    /// assert!(!Whitespace::Unicode.is_whitespace('\u{FEFF}'));
+    /// assert!(Whitespace::UnicodeAndZeroWidth.is_whitespace('\u{FEFF}'));
+    /// ```
+    pub fn is_whitespace(self, c: char) -> bool {
+        match self {
+            Whitespace::Ascii => c.is_ascii_whitespace(),
+            Whitespace::Unicode => c.is_whitespace(),
+            Whitespace::UnicodeAndZeroWidth => {
+                c.is_whitespace()
+                    || matches!(
+                        c,
+                        '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}'
+                    )
+            }
+        }
+    }
+
+    /// Returns `s` with leading and trailing whitespace removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optempty::is_blank::Whitespace;
+    ///
+    /// assert_eq!("a b", Whitespace::Unicode.trim("\u{3000}a b\n"));
+    /// assert_eq!("\u{3000}a b", Whitespace::Ascii.trim("\u{3000}a b\n"));
+    /// ```
+    pub fn trim(self, s: &str) -> &str {
+        s.trim_matches(|c| self.is_whitespace(c))
+    }
+
+    /// Returns `true` if `s` is empty or only whitespace.
+    pub(crate) fn is_blank(self, s: &str) -> bool {
+        s.chars().all(|c| self.is_whitespace(c))
+    }
+}
@@ -0,0 +1,20 @@
+extern crate std;
+use std::ffi::{OsStr, OsString};
+
+use super::{IsBlank, Whitespace};
+
+impl IsBlank for OsStr {
+    /// Returns `true` if the value is empty or only whitespace. A value that
+    /// isn't valid unicode is never blank.
+    fn is_blank_with(&self, whitespace: Whitespace) -> bool {
+        // Anything that isn't unicode has something other than whitespace in
+        // it.
+        self.to_str().is_some_and(|s| s.is_blank_with(whitespace))
+    }
+}
+
+impl IsBlank for OsString {
+    fn is_blank_with(&self, whitespace: Whitespace) -> bool {
+        self.as_os_str().is_blank_with(whitespace)
+    }
+}
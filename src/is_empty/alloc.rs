@@ -0,0 +1,96 @@
+extern crate alloc;
+
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+    ffi::CString,
+    rc::Rc,
+    string::String,
+    vec::Vec,
+};
+
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::Allocator;
+
+use super::IsEmpty;
+
+// Implement `IsEmpty` for the collections in `alloc`.
+
+#[cfg(not(feature = "allocator_api"))]
+mod global {
+    use super::*;
+
+    crate::impl_is_empty!(BinaryHeap; T);
+    crate::impl_is_empty!(BTreeMap; K, V);
+    crate::impl_is_empty!(BTreeSet; T);
+    crate::impl_is_empty!(LinkedList; T);
+    crate::impl_is_empty!(Vec; T);
+    crate::impl_is_empty!(VecDeque; T);
+}
+
+// With `allocator_api`, the same collections with any allocator.
+
+#[cfg(feature = "allocator_api")]
+mod allocator_api {
+    use super::*;
+
+    crate::impl_is_empty!([T, A: Allocator] BinaryHeap<T, A>);
+    crate::impl_is_empty!([K, V, A: Allocator + Clone] BTreeMap<K, V, A>);
+    crate::impl_is_empty!([T, A: Allocator + Clone] BTreeSet<T, A>);
+    crate::impl_is_empty!([T, A: Allocator] LinkedList<T, A>);
+    crate::impl_is_empty!([T, A: Allocator] Vec<T, A>);
+    crate::impl_is_empty!([T, A: Allocator] VecDeque<T, A>);
+}
+
+// Some other things from the stdlib.
+// TODO: Implement more from here: https://doc.rust-lang.org/std/?search=is_empty
+
+crate::impl_is_empty!(String);
+
+impl IsEmpty for CString {
+    fn is_empty(&self) -> bool {
+        self.as_bytes().is_empty()
+    }
+}
+
+// Smart pointers are empty when what they point to is empty.
+
+impl<T> IsEmpty for Box<T>
+where
+    T: IsEmpty + ?Sized,
+{
+    fn is_empty(&self) -> bool {
+        IsEmpty::is_empty(&**self)
+    }
+}
+
+impl<T> IsEmpty for Rc<T>
+where
+    T: IsEmpty + ?Sized,
+{
+    fn is_empty(&self) -> bool {
+        IsEmpty::is_empty(&**self)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> IsEmpty for Arc<T>
+where
+    T: IsEmpty + ?Sized,
+{
+    fn is_empty(&self) -> bool {
+        IsEmpty::is_empty(&**self)
+    }
+}
+
+impl<B> IsEmpty for Cow<'_, B>
+where
+    B: IsEmpty + ToOwned + ?Sized,
+{
+    fn is_empty(&self) -> bool {
+        IsEmpty::is_empty(&**self)
+    }
+}
@@ -0,0 +1,30 @@
+use axum::{extract::Query, Form, Json};
+
+use super::IsEmpty;
+
+impl<T> IsEmpty for Json<T>
+where
+    T: IsEmpty,
+{
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T> IsEmpty for Query<T>
+where
+    T: IsEmpty,
+{
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T> IsEmpty for Form<T>
+where
+    T: IsEmpty,
+{
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
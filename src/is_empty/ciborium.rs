@@ -0,0 +1,41 @@
+use ciborium::Value;
+
+use super::IsEmpty;
+
+impl IsEmpty for Value {
+    /// Returns `true` for null, and for empty text, bytes, arrays, and maps.
+    /// Otherwise, `false`.
+    ///
+    /// A tagged value is never empty, even if the value inside of it is,
+    /// since the tag carries information of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ciborium::Value;
+    /// use optempty::IsEmpty;
+    ///
+    /// assert!(Value::Null.is_empty());
+    /// assert!(Value::Text(String::new()).is_empty());
+    /// assert!(Value::Bytes(vec![]).is_empty());
+    /// assert!(Value::Array(vec![]).is_empty());
+    /// assert!(Value::Map(vec![]).is_empty());
+    ///
+    /// assert!(!Value::Integer(0.into()).is_empty());
+    /// assert!(!Value::Bool(false).is_empty());
+    /// assert!(!Value::Tag(0, Box::new(Value::Null)).is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        match self {
+            Value::Null => true,
+            Value::Integer(_) | Value::Float(_) | Value::Bool(_) | Value::Tag(..) => false,
+            Value::Text(s) => s.is_empty(),
+            Value::Bytes(b) => b.is_empty(),
+            Value::Array(a) => a.is_empty(),
+            Value::Map(m) => m.is_empty(),
+            // `Value` is `#[non_exhaustive]`. Anything new isn't known to be
+            // empty.
+            _ => false,
+        }
+    }
+}
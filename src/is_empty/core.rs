@@ -1,67 +1,53 @@
-extern crate alloc;
-
-use alloc::{
-    collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
-    string::String,
-    vec::Vec,
-};
+use core::{ffi::CStr, ops::Deref, pin::Pin};
 
 use super::IsEmpty;
 
 impl<T> IsEmpty for &T
 where
-    T: IsEmpty,
+    T: IsEmpty + ?Sized,
 {
     fn is_empty(&self) -> bool {
         IsEmpty::is_empty(*self)
     }
 }
 
-/// Just wraps the existing `is_empty(&self)` method on the type.
-// Because you can't spell `simple` without `impl`.
-macro_rules! simple_is_empty {
-    ($type:ty) => {
-        impl $crate::is_empty::IsEmpty for $type {
-            fn is_empty(&self) -> bool {
-                <$type>::is_empty(self)
-            }
-        }
-    };
-    ($type:ident; $($args:tt)*) => {
-        impl<$($args)*> $crate::is_empty::IsEmpty for $type<$($args)*> {
-            fn is_empty(&self) -> bool {
-                $type::is_empty(self)
-            }
-        }
-    };
+impl<T> IsEmpty for &mut T
+where
+    T: IsEmpty + ?Sized,
+{
+    fn is_empty(&self) -> bool {
+        IsEmpty::is_empty(*self)
+    }
 }
 
-// Implement `IsEmpty` for the std collections.
-
-simple_is_empty!(BinaryHeap; T);
-simple_is_empty!(BTreeMap; K, V);
-simple_is_empty!(BTreeSet; T);
-simple_is_empty!(LinkedList; T);
-simple_is_empty!(Vec; T);
-simple_is_empty!(VecDeque; T);
-
-// Some other things from the stdlib.
-// TODO: Implement more from here: https://doc.rust-lang.org/std/?search=is_empty
-
-simple_is_empty!(String);
+impl<P> IsEmpty for Pin<P>
+where
+    P: Deref,
+    P::Target: IsEmpty,
+{
+    fn is_empty(&self) -> bool {
+        IsEmpty::is_empty(&**self)
+    }
+}
 
-impl IsEmpty for &str {
+impl IsEmpty for str {
     fn is_empty(&self) -> bool {
-        str::is_empty(self)
+        <str>::is_empty(self)
     }
 }
 
-impl<T> IsEmpty for &[T] {
+impl<T> IsEmpty for [T] {
     fn is_empty(&self) -> bool {
         <[T]>::is_empty(self)
     }
 }
 
+impl IsEmpty for CStr {
+    fn is_empty(&self) -> bool {
+        self.to_bytes().is_empty()
+    }
+}
+
 impl<T> IsEmpty for Option<T>
 where
     T: IsEmpty,
@@ -0,0 +1,52 @@
+use http::{uri::PathAndQuery, Extensions, HeaderMap, HeaderValue};
+
+use super::IsEmpty;
+
+impl<T> IsEmpty for HeaderMap<T> {
+    fn is_empty(&self) -> bool {
+        HeaderMap::is_empty(self)
+    }
+}
+
+impl IsEmpty for HeaderValue {
+    /// Returns `true` if the value is zero bytes long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http::HeaderValue;
+    /// use optempty::IsEmpty;
+    ///
+    /// assert!(HeaderValue::from_static("").is_empty());
+    /// assert!(!HeaderValue::from_static("gzip").is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        HeaderValue::is_empty(self)
+    }
+}
+
+impl IsEmpty for Extensions {
+    fn is_empty(&self) -> bool {
+        Extensions::is_empty(self)
+    }
+}
+
+impl IsEmpty for PathAndQuery {
+    /// Returns `true` if there's no query, or the query is empty. The path is
+    /// never empty (it's at least `/`), so it isn't considered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http::uri::PathAndQuery;
+    /// use optempty::IsEmpty;
+    ///
+    /// assert!(PathAndQuery::from_static("/search").is_empty());
+    /// assert!(PathAndQuery::from_static("/search?").is_empty());
+    ///
+    /// assert!(!PathAndQuery::from_static("/search?q=rust").is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.query().is_empty()
+    }
+}
@@ -1,24 +1,221 @@
-mod no_std;
+#[cfg(feature = "alloc")]
+mod alloc;
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(feature = "ciborium")]
+mod ciborium;
+mod core;
+#[cfg(feature = "http")]
+mod http;
 #[cfg(all(feature = "querymap", feature = "std"))]
 mod query_map;
 #[cfg(feature = "serdejson")]
 mod serde_json;
+#[cfg(feature = "serde_yaml")]
+mod serde_yaml;
 #[cfg(feature = "std")]
 mod std;
+#[cfg(feature = "toml")]
+mod toml;
 
-pub use self::no_std::*;
-#[cfg(all(feature = "querymap", feature = "std"))]
-pub use self::query_map::*;
-#[cfg(feature = "serdejson")]
-pub use self::serde_json::*;
-#[cfg(feature = "std")]
-pub use self::std::*;
+#[cfg(feature = "derive")]
+pub use optempty_derive::IsEmpty;
 
 /// Used to determine if a collection, or `Option<T>` or `Result<T, E>` (where
 /// `T` implements `IsEmpty`) is empty.
 ///
-/// `IsEmpty` is implemented for the standard collections, and more.
+/// `IsEmpty` is implemented for the standard collections, and more. It is also
+/// implemented for unsized types (`str` and `[T]`), and for references and
+/// smart pointers (`Box<T>`, `Rc<T>`, `Arc<T>`, `Cow<'_, B>`, `Pin<P>`) to
+/// anything that implements `IsEmpty`.
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use optempty::EmptyIntoNone;
+///
+/// let some: Option<Arc<str>> = Some(Arc::from(""));
+/// assert_eq!(None, some.empty_into_none());
+/// ```
+///
+/// # Deriving
+///
+/// With the `derive` feature enabled, `IsEmpty` can be derived for structs and
+/// enums.
+///
+/// A struct is empty when all of its fields are empty. Fields can be left out
+/// of that check with `#[is_empty(skip)]`, or checked with some other function
+/// with `#[is_empty(with = path)]`, where `path` is a function taking a
+/// reference to the field and returning a `bool`.
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use optempty::{EmptyIntoNone, IsEmpty};
+///
+/// fn is_zero(n: &u32) -> bool {
+///     *n == 0
+/// }
+///
+/// #[derive(Debug, PartialEq, IsEmpty)]
+/// struct Dto {
+///     names: Vec<String>,
+///     nickname: Option<String>,
+///     #[is_empty(with = is_zero)]
+///     count: u32,
+///     #[is_empty(skip)]
+///     version: u32,
+/// }
+///
+/// let dto = Dto {
+///     names: vec![],
+///     nickname: None,
+///     count: 0,
+///     version: 3,
+/// };
+/// assert!(dto.is_empty());
+/// assert_eq!(None, Some(dto).empty_into_none());
+/// # }
+/// ```
+///
+/// An enum is empty when it is one of the variants marked `#[is_empty]`.
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use optempty::IsEmpty;
+///
+/// #[derive(IsEmpty)]
+/// enum Filter {
+///     #[is_empty]
+///     None,
+///     Name(String),
+///     Tags(Vec<String>),
+/// }
+///
+/// assert!(Filter::None.is_empty());
+/// // Unmarked variants are never empty, regardless of their fields.
+/// assert!(!Filter::Tags(vec![]).is_empty());
+/// # }
+/// ```
+///
+/// # Hashers
+///
+/// `IsEmpty` is implemented for `HashMap<K, V, S>` and `HashSet<T, S>` with
+/// any hasher `S`, so an `IsEmpty` bound doesn't pick the hasher. A map made
+/// with `HashMap::default()` needs it spelled out, e.g., with a
+/// `HashMap<_, _>` annotation, or can be made with `HashMap::new()` instead.
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # {
+/// use std::collections::HashMap;
+///
+/// use optempty::EmptyIntoNone;
+///
+/// let mut map: HashMap<_, _> = HashMap::default();
+/// assert!(Some(map.clone()).empty_into_none().is_none());
+///
+/// map.insert("a", 1);
+/// assert!(Some(map).empty_into_none().is_some());
+/// # }
+/// ```
 pub trait IsEmpty {
     /// Returns `true` if it is empty.
     fn is_empty(&self) -> bool;
 }
+
+/// Implements [`IsEmpty`] for a type that already has an inherent
+/// `is_empty(&self) -> bool` method, by calling that method.
+///
+/// # Examples
+///
+/// A type without generics:
+/// ```
+/// # use optempty::IsEmpty;
+/// #
+/// struct Names(Vec<String>);
+///
+/// impl Names {
+///     fn is_empty(&self) -> bool {
+///         self.0.is_empty()
+///     }
+/// }
+///
+/// optempty::impl_is_empty!(Names);
+///
+/// assert!(IsEmpty::is_empty(&Names(vec![])));
+/// ```
+///
+/// A type with generic type parameters lists them after a `;`, optionally
+/// followed by a `where` clause:
+/// ```
+/// # use optempty::IsEmpty;
+/// #
+/// struct Bag<T, U>(Vec<(T, U)>);
+///
+/// impl<T, U> Bag<T, U>
+/// where
+///     T: Clone,
+/// {
+///     fn is_empty(&self) -> bool {
+///         self.0.is_empty()
+///     }
+/// }
+///
+/// optempty::impl_is_empty!(Bag; T, U where T: Clone);
+///
+/// assert!(IsEmpty::is_empty(&Bag::<u8, u8>(vec![])));
+/// ```
+///
+/// Anything else, such as lifetimes or const generics, can be written out in
+/// full with the generic parameters in `[]`, followed by the type, and an
+/// optional `where` clause:
+/// ```
+/// # use optempty::IsEmpty;
+/// #
+/// struct Buffer<'a, T, const N: usize> {
+///     items: &'a [T; N],
+///     len: usize,
+/// }
+///
+/// impl<'a, T, const N: usize> Buffer<'a, T, N> {
+///     fn is_empty(&self) -> bool {
+///         self.len == 0
+///     }
+/// }
+///
+/// optempty::impl_is_empty!(['a, T, const N: usize] Buffer<'a, T, N> where T: Copy);
+///
+/// let buffer = Buffer {
+///     items: &[1, 2, 3],
+///     len: 0,
+/// };
+/// assert!(IsEmpty::is_empty(&buffer));
+/// ```
+///
+/// [`IsEmpty`]: crate::is_empty::IsEmpty
+#[macro_export]
+macro_rules! impl_is_empty {
+    ([$($generics:tt)*] $type:ty $(where $($bounds:tt)+)?) => {
+        impl<$($generics)*> $crate::is_empty::IsEmpty for $type $(where $($bounds)+)? {
+            fn is_empty(&self) -> bool {
+                <$type>::is_empty(self)
+            }
+        }
+    };
+    ($type:ty) => {
+        impl $crate::is_empty::IsEmpty for $type {
+            fn is_empty(&self) -> bool {
+                <$type>::is_empty(self)
+            }
+        }
+    };
+    ($type:ident; $($args:ident),+ $(where $($bounds:tt)+)?) => {
+        impl<$($args),+> $crate::is_empty::IsEmpty for $type<$($args),+> $(where $($bounds)+)? {
+            fn is_empty(&self) -> bool {
+                $type::is_empty(self)
+            }
+        }
+    };
+}
@@ -1,7 +1,7 @@
 extern crate alloc;
 use alloc::string::String;
 
-use serde_json::{Map, Value};
+use serde_json::{value::RawValue, Map, Value};
 
 use super::IsEmpty;
 
@@ -10,3 +10,67 @@ impl IsEmpty for Map<String, Value> {
         Map::is_empty(self)
     }
 }
+
+impl IsEmpty for Value {
+    /// Returns `true` for `null`, `""`, `[]`, and `{}`. Otherwise, `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optempty::IsEmpty;
+    /// use serde_json::json;
+    ///
+    /// assert!(json!(null).is_empty());
+    /// assert!(json!("").is_empty());
+    /// assert!(json!([]).is_empty());
+    /// assert!(json!({}).is_empty());
+    ///
+    /// assert!(!json!(0).is_empty());
+    /// assert!(!json!(false).is_empty());
+    /// assert!(!json!([null]).is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        match self {
+            Value::Null => true,
+            Value::Bool(_) | Value::Number(_) => false,
+            Value::String(s) => s.is_empty(),
+            Value::Array(a) => a.is_empty(),
+            Value::Object(o) => o.is_empty(),
+        }
+    }
+}
+
+impl IsEmpty for RawValue {
+    /// Returns `true` if the raw JSON text is `null`, `""`, `[]`, or `{}`
+    /// (allowing for whitespace). Otherwise, `false`.
+    ///
+    /// The same as parsing it to a [`Value`] and calling `is_empty()`, without
+    /// the parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optempty::IsEmpty;
+    /// use serde_json::value::RawValue;
+    ///
+    /// let raw = RawValue::from_string("[ ]".into()).unwrap();
+    /// assert!(raw.is_empty());
+    ///
+    /// let raw = RawValue::from_string(r#"{"a":1}"#.into()).unwrap();
+    /// assert!(!raw.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        fn is_whitespace(b: &u8) -> bool {
+            matches!(b, b' ' | b'\t' | b'\n' | b'\r')
+        }
+
+        let raw = self
+            .get()
+            .trim_matches(|c| matches!(c, ' ' | '\t' | '\n' | '\r'));
+        match raw.as_bytes() {
+            b"null" | b"\"\"" => true,
+            [b'[', inner @ .., b']'] | [b'{', inner @ .., b'}'] => inner.iter().all(is_whitespace),
+            _ => false,
+        }
+    }
+}
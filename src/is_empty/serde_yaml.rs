@@ -0,0 +1,45 @@
+use serde_yaml::{Mapping, Value};
+
+use super::IsEmpty;
+
+impl IsEmpty for Mapping {
+    fn is_empty(&self) -> bool {
+        Mapping::is_empty(self)
+    }
+}
+
+impl IsEmpty for Value {
+    /// Returns `true` for `null`, `""`, `[]`, and `{}`. Otherwise, `false`.
+    ///
+    /// A tagged value is never empty, even if the value inside of it is,
+    /// since the tag carries information of its own.
+    ///
+    /// `serde_yaml::Sequence` is a `Vec<Value>`, so it's covered by the `Vec`
+    /// impl.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optempty::IsEmpty;
+    /// use serde_yaml::Value;
+    ///
+    /// for yaml in ["~", "''", "[]", "{}"] {
+    ///     let value: Value = serde_yaml::from_str(yaml).unwrap();
+    ///     assert!(value.is_empty(), "{yaml}");
+    /// }
+    ///
+    /// for yaml in ["0", "false", "[~]", "!tag ''"] {
+    ///     let value: Value = serde_yaml::from_str(yaml).unwrap();
+    ///     assert!(!value.is_empty(), "{yaml}");
+    /// }
+    /// ```
+    fn is_empty(&self) -> bool {
+        match self {
+            Value::Null => true,
+            Value::Bool(_) | Value::Number(_) | Value::Tagged(_) => false,
+            Value::String(s) => s.is_empty(),
+            Value::Sequence(s) => s.is_empty(),
+            Value::Mapping(m) => m.is_empty(),
+        }
+    }
+}
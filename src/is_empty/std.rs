@@ -1,29 +1,38 @@
 extern crate std;
-use std::collections::{HashMap, HashSet};
-
-/// Just wraps the existing `is_empty(&self)` method on the type.
-// Because you can't spell `simple` without `impl`.
-macro_rules! simple_is_empty {
-    ($type:ty) => {
-        impl $crate::is_empty::IsEmpty for $type {
-            fn is_empty(&self) -> bool {
-                <$type>::is_empty(self)
-            }
-        }
-    };
-    ($type:ident; $($args:tt)*) => {
-        impl<$($args)*> $crate::is_empty::IsEmpty for $type<$($args)*> {
-            fn is_empty(&self) -> bool {
-                $type::is_empty(self)
-            }
-        }
-    };
-}
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
+};
+
+use super::IsEmpty;
 
 // Implement `IsEmpty` for the std collections.
 
-simple_is_empty!(HashMap; K, V);
-simple_is_empty!(HashSet; T);
+crate::impl_is_empty!(HashMap; K, V, S);
+crate::impl_is_empty!(HashSet; T, S);
 
 // Some other things from the stdlib.
 // TODO: Implement more from here: https://doc.rust-lang.org/std/?search=is_empty
+
+crate::impl_is_empty!(OsStr);
+
+impl IsEmpty for OsString {
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+impl IsEmpty for Path {
+    /// Returns `true` if the path has no components at all, i.e.,
+    /// `as_os_str().is_empty()`.
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+impl IsEmpty for PathBuf {
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
@@ -0,0 +1,36 @@
+use toml::{Table, Value};
+
+use super::IsEmpty;
+
+impl IsEmpty for Table {
+    fn is_empty(&self) -> bool {
+        Table::is_empty(self)
+    }
+}
+
+impl IsEmpty for Value {
+    /// Returns `true` for `""`, `[]`, and `{}`. Otherwise, `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optempty::IsEmpty;
+    /// use toml::Value;
+    ///
+    /// assert!(Value::String(String::new()).is_empty());
+    /// assert!(Value::Array(vec![]).is_empty());
+    /// assert!(Value::Table(toml::Table::new()).is_empty());
+    ///
+    /// assert!(!Value::Integer(0).is_empty());
+    /// assert!(!Value::Boolean(false).is_empty());
+    /// assert!(!Value::Array(vec![Value::String(String::new())]).is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        match self {
+            Value::Integer(_) | Value::Float(_) | Value::Boolean(_) | Value::Datetime(_) => false,
+            Value::String(s) => s.is_empty(),
+            Value::Array(a) => a.is_empty(),
+            Value::Table(t) => t.is_empty(),
+        }
+    }
+}
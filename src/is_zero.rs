@@ -0,0 +1,68 @@
+use core::{num::Wrapping, time::Duration};
+
+/// Used to determine if a number (or `Duration`) is zero.
+///
+/// For fields where `0` means "unset", the same way an empty string does.
+///
+/// `IsZero` is implemented for all primitive integers and floats, `Duration`,
+/// `Wrapping<T>` where `T` implements `IsZero`, and references to anything
+/// that implements `IsZero`.
+///
+/// # Examples
+///
+/// ```
+/// use std::{num::Wrapping, time::Duration};
+///
+/// use optempty::IsZero;
+///
+/// assert!(0_u32.is_zero());
+/// assert!(!1_i8.is_zero());
+/// assert!((-0.0_f64).is_zero());
+/// assert!(!f32::NAN.is_zero());
+/// assert!(Duration::ZERO.is_zero());
+/// assert!((Wrapping(u8::MAX) + Wrapping(1)).is_zero());
+/// ```
+pub trait IsZero {
+    /// Returns `true` if the value is zero.
+    fn is_zero(&self) -> bool;
+}
+
+impl<T> IsZero for &T
+where
+    T: IsZero + ?Sized,
+{
+    fn is_zero(&self) -> bool {
+        IsZero::is_zero(*self)
+    }
+}
+
+macro_rules! impl_is_zero {
+    ($zero:literal; $($ty:ty),* $(,)?) => {
+        $(
+            impl IsZero for $ty {
+                fn is_zero(&self) -> bool {
+                    *self == $zero
+                }
+            }
+        )*
+    };
+}
+
+impl_is_zero!(0; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+// `-0.0 == 0.0`, so negative zero is zero too. `NaN` isn't.
+impl_is_zero!(0.0; f32, f64);
+
+impl IsZero for Duration {
+    fn is_zero(&self) -> bool {
+        Duration::is_zero(self)
+    }
+}
+
+impl<T> IsZero for Wrapping<T>
+where
+    T: IsZero,
+{
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
@@ -70,36 +70,273 @@
 //! assert_eq!(Err("failed"), still_err);
 //! ```
 //!
+//! ## `none_into_empty`
+//!
+//! The inverse of `empty_into_none`. `None` becomes an empty `Vec`.
+//! ```
+//! use optempty::*;
+//!
+//! let none: Option<Vec<&str>> = None;
+//! let empty = none.none_into_empty();
+//! assert_eq!(Vec::<&str>::new(), empty);
+//! ```
+//!
+//! `Some` is unwrapped.
+//! ```
+//! # use optempty::*;
+//! #
+//! let some = Some(vec!["a", "b", "c"]);
+//! let v = some.none_into_empty();
+//! assert_eq!(vec!["a", "b", "c"], v);
+//! ```
+//!
+//! ## `into_non_empty`
+//!
+//! Like `empty_into_none`, but the non-empty value is kept in a type that
+//! can't be empty.
+//! ```
+//! use optempty::*;
+//!
+//! let some = Some(vec!["a", "b", "c"]);
+//! let non_empty = some.into_non_empty().unwrap();
+//! assert_eq!(&"a", non_empty.first());
+//! ```
+//!
 //! See more examples at:
 //! * [`IsEmpty`]
 //! * [`EmptyIntoNone`]
 //! * [`EmptyIntoErr`]
+//! * [`Empty`]
+//! * [`NoneIntoEmpty`]
+//! * [`ParseNonEmpty`]
+//! * [`IsBlank`]
+//! * [`BlankIntoNone`]
+//! * [`BlankIntoErr`]
+//! * [`TrimIntoNone`]
+//! * [`IsZero`]
+//! * [`ZeroIntoNone`]
+//! * [`ZeroIntoErr`]
+//! * [`non_empty`]
 //!
 //! # Features
 //!
 //! Available features are:
+//! * `alloc`
+//!   * Adds support for types from `alloc` (`Vec`, `String`, `BTreeMap`,
+//!     etc.), and the [`non_empty`] module.
+//! * `allocator_api`
+//!   * Nightly only. Makes the `alloc` collection impls generic over the
+//!     allocator, e.g., `Vec<T, A>` rather than only `Vec<T>`.
+//!   * Implies `alloc`.
+//! * `axum`
+//!   * Adds the [`axum`] module, with extractors that reject or skip empty
+//!     input, and support for `axum::Json`, `axum::extract::Query`, and
+//!     `axum::Form`.
+//!   * Implies `std`.
+//! * `ciborium`
+//!   * Adds support for [`ciborium::Value`], including [`PruneEmpty`].
+//!   * Implies `std`.
+//! * `clap`
+//!   * Adds the [`clap`] module, with value parsers that reject or skip empty
+//!     arguments.
+//!   * Implies `std`.
+//! * `derive`
+//!   * Adds `#[derive(IsEmpty)]` for your own structs and enums. See [`IsEmpty`].
+//!   * With `serde`, adds the [`omit_empty`] attribute, which leaves out empty
+//!     fields when serializing.
+//! * `http`
+//!   * Adds support for `http::HeaderMap`, `http::HeaderValue`,
+//!     `http::Extensions`, and `http::uri::PathAndQuery`, and the [`http`]
+//!     module for treating empty header values as missing.
+//!   * Implies `std`.
+//! * `http-body`
+//!   * Adds the [`http_body`] module, for treating empty `http_body::Body`s
+//!     as missing without buffering them.
+//!   * Implies `std`.
 //! * `querymap`
-//!   * Adds support for [`query_map::QueryMap`]
+//!   * Adds support for [`QueryMap`], and the [`query_map`] module for
+//!     treating empty query parameters as missing.
+//! * `serde`
+//!   * Adds the [`serde`] module, with helpers for
+//!     `skip_serializing_if` and for deserializing empty values as `None`.
+//!   * Adds [`serde::OmitEmpty`], which leaves out empty fields when
+//!     serializing any `Serialize` type.
+//!   * With `alloc`, adds [`serde::de::OmitEmpty`], which wraps any
+//!     self-describing deserializer so that map entries and struct fields
+//!     with empty values are left out.
+//! * `serde_yaml`
+//!   * Adds support for [`serde_yaml::Value`] and [`serde_yaml::Mapping`]
+//!     (`serde_yaml::Sequence` is a `Vec`), including [`PruneEmpty`].
+//!   * Implies `std`.
 //! * `serdejson`
-//!   * Adds support for [`serde_json::Map`]
+//!   * Adds support for [`serde_json::Map`], [`serde_json::Value`], and
+//!     [`serde_json::value::RawValue`]
+//!   * Adds [`PruneEmpty`] for `serde_json::Value` and `serde_json::Map`
 //! * `std`
-//!   * Adds support for types in `std::collections` in addition to types from `alloc`.
+//!   * Adds support for types in `std::collections`, `std::ffi`, and
+//!     `std::path` in addition to types from `alloc`.
+//!   * Adds the [`env`] module, for reading environment variables that may be
+//!     set but empty.
+//!   * Implies `alloc`.
+//! * `toml`
+//!   * Adds support for [`toml::Value`] and [`toml::Table`], including
+//!     [`PruneEmpty`].
+//!   * Implies `std`.
+//! * `url`
+//!   * Adds the [`url`] module, for treating empty parts of a `url::Url` as
+//!     missing.
+//!   * Implies `std`.
 //!
 //! Default features:
 //! * `std`
 //!
+//! With no features enabled, only `core` is needed. [`IsEmpty`], [`Empty`],
+//! [`EmptyIntoNone`], [`EmptyIntoErr`], and [`NoneIntoEmpty`] all still work
+//! with `&str`, `&[T]`, `Option<T>`, and `Result<T, E>`, and
+//! [`ParseNonEmpty`], [`IsBlank`], [`BlankIntoNone`], [`BlankIntoErr`], and
+//! [`TrimIntoNone`] with `&str`. [`IsZero`], [`ZeroIntoNone`], and
+//! [`ZeroIntoErr`] only need `core`.
+//!
 //! [Option]: std::option::Option
 //! [IsEmpty]: crate::is_empty::IsEmpty
 //! [EmptyIntoNone]: crate::empty_into_none::EmptyIntoNone
 //! [EmptyIntoErr]: crate::empty_into_err::EmptyIntoErr
+//! [Empty]: crate::empty::Empty
+//! [NoneIntoEmpty]: crate::none_into_empty::NoneIntoEmpty
+//! [ParseNonEmpty]: crate::parse_non_empty::ParseNonEmpty
+//! [IsBlank]: crate::is_blank::IsBlank
+//! [BlankIntoNone]: crate::blank_into_none::BlankIntoNone
+//! [BlankIntoErr]: crate::blank_into_err::BlankIntoErr
+//! [TrimIntoNone]: crate::trim_into_none::TrimIntoNone
+//! [IsZero]: crate::is_zero::IsZero
+//! [ZeroIntoNone]: crate::zero_into_none::ZeroIntoNone
+//! [ZeroIntoErr]: crate::zero_into_err::ZeroIntoErr
+//! [non_empty]: crate::non_empty
+//! [`PruneEmpty`]: crate::prune::PruneEmpty
+//! [`QueryMap`]: ::query_map::QueryMap
+//! [`env`]: mod@crate::env
 
 #![deny(warnings)]
-#![cfg_attr(not(feature = "use_std"), no_std)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
 
+#[cfg(feature = "axum")]
+pub mod axum;
+pub mod blank_into_err;
+pub mod blank_into_none;
+#[cfg(feature = "clap")]
+pub mod clap;
+pub mod empty;
 pub mod empty_into_err;
 pub mod empty_into_none;
+#[cfg(feature = "std")]
+pub mod env;
+#[cfg(feature = "http")]
+pub mod http;
+#[cfg(feature = "http-body")]
+pub mod http_body;
+pub mod is_blank;
 pub mod is_empty;
+pub mod is_zero;
+#[cfg(feature = "alloc")]
+pub mod non_empty;
+pub mod none_into_empty;
+pub mod parse_non_empty;
+#[cfg(any(
+    feature = "serdejson",
+    feature = "toml",
+    feature = "serde_yaml",
+    feature = "ciborium"
+))]
+pub mod prune;
+#[cfg(all(feature = "querymap", feature = "std"))]
+pub mod query_map;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod trim_into_none;
+#[cfg(feature = "url")]
+pub mod url;
+pub mod zero_into_err;
+pub mod zero_into_none;
 
+pub use blank_into_err::BlankIntoErr;
+pub use blank_into_none::BlankIntoNone;
+pub use empty::Empty;
 pub use empty_into_err::EmptyIntoErr;
 pub use empty_into_none::EmptyIntoNone;
+pub use is_blank::IsBlank;
 pub use is_empty::IsEmpty;
+pub use is_zero::IsZero;
+#[cfg(feature = "alloc")]
+pub use non_empty::IntoNonEmpty;
+pub use none_into_empty::NoneIntoEmpty;
+pub use parse_non_empty::ParseNonEmpty;
+pub use trim_into_none::TrimIntoNone;
+pub use zero_into_err::ZeroIntoErr;
+pub use zero_into_none::ZeroIntoNone;
+
+/// Leaves out empty fields when serializing, by adding
+/// `#[serde(skip_serializing_if = ...)]` to every named field.
+///
+/// Place it above `#[derive(Serialize)]`, so that it runs first. It works on
+/// structs, and on the fields of struct-like enum variants.
+///
+/// * `#[omit_empty(keep)]` on a field leaves it alone.
+/// * `#[omit_empty(default)]` on the type also adds `#[serde(default)]` to
+///   each field, other than those marked `keep`, so that what was serialized
+///   can be deserialized again. Those fields' types need to implement
+///   `Default`.
+///
+/// Fields that already have `#[serde(skip)]`, `#[serde(skip_serializing)]`, or
+/// `#[serde(skip_serializing_if = ...)]` are not changed.
+///
+/// A field whose type implements [`IsEmpty`] is left out when it's empty.
+/// Any other field, such as an `Option<u32>`, or one whose type is a generic
+/// parameter like `T`, is left out when it serializes as an empty value, as
+/// with [`OmitEmpty`]: `None`, `""`, unit, or an empty sequence or map. Fields
+/// with `#[serde(serialize_with = ...)]` or `#[serde(with = ...)]` are only
+/// left out if their type implements [`IsEmpty`].
+///
+/// Fields of tuple structs and tuple variants are never left out, since that
+/// would shift the fields after them.
+///
+/// # Examples
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[optempty::omit_empty(default)]
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Person {
+///     name: String,
+///     nickname: Option<String>,
+///     aliases: Vec<String>,
+///     #[omit_empty(keep)]
+///     tags: Vec<String>,
+///     // Numbers are never empty, so it's always serialized.
+///     age: u32,
+///     // Left out when `None`.
+///     height: Option<u32>,
+/// }
+///
+/// let person = Person {
+///     name: "Zoe".into(),
+///     nickname: Some(String::new()),
+///     aliases: vec![],
+///     tags: vec![],
+///     age: 30,
+///     height: None,
+/// };
+///
+/// let json = serde_json::to_string(&person).unwrap();
+/// assert_eq!(r#"{"name":"Zoe","tags":[],"age":30}"#, json);
+///
+/// // `nickname` is `None` rather than `Some("")`, since it was left out.
+/// let person: Person = serde_json::from_str(&json).unwrap();
+/// assert_eq!(None, person.nickname);
+/// ```
+///
+/// [`IsEmpty`]: crate::is_empty::IsEmpty
+/// [`OmitEmpty`]: crate::serde::ser::OmitEmpty
+#[cfg(all(feature = "derive", feature = "serde"))]
+pub use optempty_derive::omit_empty;
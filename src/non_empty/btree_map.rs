@@ -0,0 +1,124 @@
+extern crate alloc;
+
+use alloc::collections::{btree_map, BTreeMap};
+use core::ops::Deref;
+
+use super::{into_non_empty, EmptyError};
+use crate::is_empty::IsEmpty;
+
+/// A `BTreeMap<K, V>` that is never empty.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use optempty::non_empty::NonEmptyBTreeMap;
+///
+/// let map = NonEmptyBTreeMap::try_from(BTreeMap::from([("b", 2), ("a", 1)])).unwrap();
+/// assert_eq!((&"a", &1), map.first());
+/// assert_eq!((&"b", &2), map.last());
+/// assert_eq!((&"b", &2), map.max());
+///
+/// assert!(NonEmptyBTreeMap::<&str, u32>::try_from(BTreeMap::new()).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NonEmptyBTreeMap<K, V>(BTreeMap<K, V>);
+
+impl<K, V> NonEmptyBTreeMap<K, V> {
+    /// Makes a new `NonEmptyBTreeMap` with a single entry.
+    pub fn new(key: K, value: V) -> Self
+    where
+        K: Ord,
+    {
+        Self(BTreeMap::from([(key, value)]))
+    }
+
+    /// Returns the entry with the smallest key.
+    pub fn first(&self) -> (&K, &V) {
+        self.0
+            .iter()
+            .next()
+            .expect("NonEmptyBTreeMap is never empty")
+    }
+
+    /// Returns the entry with the greatest key.
+    pub fn last(&self) -> (&K, &V) {
+        self.0
+            .iter()
+            .next_back()
+            .expect("NonEmptyBTreeMap is never empty")
+    }
+
+    /// Returns the entry with the greatest key. The same as
+    /// [`last`](Self::last).
+    pub fn max(&self) -> (&K, &V) {
+        self.last()
+    }
+
+    /// Inserts an entry, returning the old value for the key, if there was
+    /// one.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        K: Ord,
+    {
+        self.0.insert(key, value)
+    }
+
+    /// Returns the inner `BTreeMap<K, V>`.
+    pub fn into_inner(self) -> BTreeMap<K, V> {
+        self.0
+    }
+}
+
+impl<K, V> TryFrom<BTreeMap<K, V>> for NonEmptyBTreeMap<K, V> {
+    type Error = EmptyError;
+
+    fn try_from(value: BTreeMap<K, V>) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            Err(EmptyError)
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl<K, V> From<NonEmptyBTreeMap<K, V>> for BTreeMap<K, V> {
+    fn from(value: NonEmptyBTreeMap<K, V>) -> Self {
+        value.0
+    }
+}
+
+impl<K, V> Deref for NonEmptyBTreeMap<K, V> {
+    type Target = BTreeMap<K, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K, V> IntoIterator for NonEmptyBTreeMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = btree_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a NonEmptyBTreeMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = btree_map::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<K, V> IsEmpty for NonEmptyBTreeMap<K, V> {
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+into_non_empty!(BTreeMap<K, V> => NonEmptyBTreeMap<K, V>; K, V);
@@ -0,0 +1,157 @@
+extern crate std;
+
+use core::ops::Deref;
+use std::{
+    collections::{hash_map, HashMap},
+    hash::{BuildHasher, Hash},
+};
+
+use super::{into_non_empty, EmptyError};
+use crate::is_empty::IsEmpty;
+
+/// A `HashMap<K, V, S>` that is never empty.
+///
+/// Since `HashMap`s are unordered, [`first`](Self::first) and
+/// [`last`](Self::last) are in iteration order, which is arbitrary.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use optempty::non_empty::NonEmptyHashMap;
+///
+/// let map = NonEmptyHashMap::try_from(HashMap::from([("b", 2), ("a", 1)])).unwrap();
+/// assert_eq!((&"b", &2), map.max());
+///
+/// assert!(NonEmptyHashMap::<&str, u32>::try_from(HashMap::new()).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct NonEmptyHashMap<K, V, S = hash_map::RandomState>(HashMap<K, V, S>);
+
+impl<K, V> NonEmptyHashMap<K, V> {
+    /// Makes a new `NonEmptyHashMap` with a single entry.
+    pub fn new(key: K, value: V) -> Self
+    where
+        K: Eq + Hash,
+    {
+        Self(HashMap::from([(key, value)]))
+    }
+}
+
+impl<K, V, S> NonEmptyHashMap<K, V, S> {
+    /// Returns the first entry, in iteration order.
+    pub fn first(&self) -> (&K, &V) {
+        self.0
+            .iter()
+            .next()
+            .expect("NonEmptyHashMap is never empty")
+    }
+
+    /// Returns the last entry, in iteration order.
+    ///
+    /// This iterates over all entries.
+    pub fn last(&self) -> (&K, &V) {
+        self.0
+            .iter()
+            .last()
+            .expect("NonEmptyHashMap is never empty")
+    }
+
+    /// Returns the entry with the greatest key.
+    pub fn max(&self) -> (&K, &V)
+    where
+        K: Ord,
+    {
+        self.0
+            .iter()
+            .max_by(|(a, _), (b, _)| a.cmp(b))
+            .expect("NonEmptyHashMap is never empty")
+    }
+
+    /// Inserts an entry, returning the old value for the key, if there was
+    /// one.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        K: Eq + Hash,
+        S: BuildHasher,
+    {
+        self.0.insert(key, value)
+    }
+
+    /// Returns the inner `HashMap<K, V, S>`.
+    pub fn into_inner(self) -> HashMap<K, V, S> {
+        self.0
+    }
+}
+
+impl<K, V, S> PartialEq for NonEmptyHashMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K, V, S> Eq for NonEmptyHashMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: Eq,
+    S: BuildHasher,
+{
+}
+
+impl<K, V, S> TryFrom<HashMap<K, V, S>> for NonEmptyHashMap<K, V, S> {
+    type Error = EmptyError;
+
+    fn try_from(value: HashMap<K, V, S>) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            Err(EmptyError)
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl<K, V, S> From<NonEmptyHashMap<K, V, S>> for HashMap<K, V, S> {
+    fn from(value: NonEmptyHashMap<K, V, S>) -> Self {
+        value.0
+    }
+}
+
+impl<K, V, S> Deref for NonEmptyHashMap<K, V, S> {
+    type Target = HashMap<K, V, S>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K, V, S> IntoIterator for NonEmptyHashMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = hash_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a NonEmptyHashMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = hash_map::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<K, V, S> IsEmpty for NonEmptyHashMap<K, V, S> {
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+into_non_empty!(HashMap<K, V, S> => NonEmptyHashMap<K, V, S>; K, V, S);
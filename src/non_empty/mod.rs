@@ -0,0 +1,109 @@
+//! Collections that are known to not be empty.
+//!
+//! Each type can be made from its empty-able counterpart with `TryFrom`
+//! (which fails on empty values), or with [`IntoNonEmpty::into_non_empty`].
+//! Since they are never empty, things like `first()` and `last()` don't need
+//! to return an `Option`.
+//!
+//! # Examples
+//!
+//! ```
+//! use optempty::IntoNonEmpty;
+//!
+//! let some = Some(vec![3, 1, 2]);
+//! let non_empty = some.into_non_empty().unwrap();
+//! assert_eq!(&3, non_empty.first());
+//! assert_eq!(&2, non_empty.last());
+//! assert_eq!(&3, non_empty.max());
+//!
+//! // Derefs to a slice.
+//! assert_eq!(3, non_empty.len());
+//!
+//! let some_empty: Option<Vec<u32>> = Some(vec![]);
+//! assert_eq!(None, some_empty.into_non_empty());
+//! ```
+//!
+//! [`IntoNonEmpty::into_non_empty`]: crate::non_empty::IntoNonEmpty::into_non_empty
+
+mod btree_map;
+#[cfg(feature = "std")]
+mod hash_map;
+mod string;
+mod vec;
+
+use core::fmt;
+
+pub use self::btree_map::NonEmptyBTreeMap;
+#[cfg(feature = "std")]
+pub use self::hash_map::NonEmptyHashMap;
+pub use self::string::NonEmptyString;
+pub use self::vec::NonEmptyVec;
+
+/// Returned when trying to make one of the non-empty types from an empty value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct EmptyError;
+
+impl fmt::Display for EmptyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("value was empty")
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for EmptyError {}
+
+/// Import this trait to add the `into_non_empty()` method to collections that
+/// have a non-empty counterpart in this module, and to `Option`s of them.
+///
+/// It's like [`EmptyIntoNone::empty_into_none`], but keeps the knowledge that
+/// the value isn't empty in the type.
+///
+/// [`EmptyIntoNone::empty_into_none`]: crate::empty_into_none::EmptyIntoNone::empty_into_none
+pub trait IntoNonEmpty {
+    /// The non-empty counterpart of `Self`.
+    type NonEmpty;
+
+    /// Returns `None` if the value is empty, otherwise it is returned as
+    /// [`Self::NonEmpty`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optempty::{non_empty::NonEmptyString, IntoNonEmpty};
+    ///
+    /// let name = String::from("Zoe").into_non_empty();
+    /// assert_eq!(Some('Z'), name.as_ref().map(NonEmptyString::first));
+    ///
+    /// assert_eq!(None, String::new().into_non_empty());
+    ///
+    /// let none: Option<String> = None;
+    /// assert_eq!(None, none.into_non_empty());
+    /// ```
+    fn into_non_empty(self) -> Option<Self::NonEmpty>;
+}
+
+impl<T> IntoNonEmpty for Option<T>
+where
+    T: IntoNonEmpty,
+{
+    type NonEmpty = T::NonEmpty;
+
+    fn into_non_empty(self) -> Option<Self::NonEmpty> {
+        self.and_then(IntoNonEmpty::into_non_empty)
+    }
+}
+
+/// Implements `IntoNonEmpty` with the type's `TryFrom` impl.
+macro_rules! into_non_empty {
+    ($type:ty => $non_empty:ty; $($args:tt)*) => {
+        impl<$($args)*> $crate::non_empty::IntoNonEmpty for $type {
+            type NonEmpty = $non_empty;
+
+            fn into_non_empty(self) -> Option<Self::NonEmpty> {
+                <$non_empty>::try_from(self).ok()
+            }
+        }
+    };
+}
+
+use into_non_empty;
@@ -0,0 +1,116 @@
+extern crate alloc;
+
+use alloc::string::String;
+use core::{fmt, ops::Deref};
+
+use super::{into_non_empty, EmptyError};
+use crate::is_empty::IsEmpty;
+
+/// A `String` that is never empty.
+///
+/// # Examples
+///
+/// ```
+/// use optempty::non_empty::NonEmptyString;
+///
+/// let s = NonEmptyString::try_from(String::from("hello")).unwrap();
+/// assert_eq!('h', s.first());
+/// assert_eq!('o', s.last());
+/// assert_eq!('o', s.max());
+///
+/// assert!(NonEmptyString::try_from(String::new()).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NonEmptyString(String);
+
+impl NonEmptyString {
+    /// Makes a new `NonEmptyString` with a single `char`.
+    pub fn new(first: char) -> Self {
+        Self(String::from(first))
+    }
+
+    /// Returns the first `char`.
+    pub fn first(&self) -> char {
+        self.0
+            .chars()
+            .next()
+            .expect("NonEmptyString is never empty")
+    }
+
+    /// Returns the last `char`.
+    pub fn last(&self) -> char {
+        self.0
+            .chars()
+            .next_back()
+            .expect("NonEmptyString is never empty")
+    }
+
+    /// Returns the greatest `char`.
+    pub fn max(&self) -> char {
+        self.0.chars().max().expect("NonEmptyString is never empty")
+    }
+
+    /// Appends a string slice to the end.
+    pub fn push_str(&mut self, string: &str) {
+        self.0.push_str(string);
+    }
+
+    /// Returns the inner `String`.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl TryFrom<String> for NonEmptyString {
+    type Error = EmptyError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            Err(EmptyError)
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl TryFrom<&str> for NonEmptyString {
+    type Error = EmptyError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_from(String::from(value))
+    }
+}
+
+impl From<NonEmptyString> for String {
+    fn from(value: NonEmptyString) -> Self {
+        value.0
+    }
+}
+
+impl Deref for NonEmptyString {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for NonEmptyString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for NonEmptyString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl IsEmpty for NonEmptyString {
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+into_non_empty!(String => NonEmptyString;);
@@ -0,0 +1,118 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::{ops::Deref, slice};
+
+use super::{into_non_empty, EmptyError};
+use crate::is_empty::IsEmpty;
+
+/// A `Vec<T>` that is never empty.
+///
+/// # Examples
+///
+/// ```
+/// use optempty::non_empty::NonEmptyVec;
+///
+/// let v = NonEmptyVec::try_from(vec!["b", "c", "a"]).unwrap();
+/// assert_eq!(&"b", v.first());
+/// assert_eq!(&"a", v.last());
+/// assert_eq!(&"c", v.max());
+///
+/// assert!(NonEmptyVec::<&str>::try_from(vec![]).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NonEmptyVec<T>(Vec<T>);
+
+impl<T> NonEmptyVec<T> {
+    /// Makes a new `NonEmptyVec` with a single element.
+    pub fn new(first: T) -> Self {
+        Self(alloc::vec![first])
+    }
+
+    /// Returns the first element.
+    pub fn first(&self) -> &T {
+        &self.0[0]
+    }
+
+    /// Returns the last element.
+    pub fn last(&self) -> &T {
+        &self.0[self.0.len() - 1]
+    }
+
+    /// Returns the greatest element. If several elements are equally
+    /// greatest, the last one is returned.
+    pub fn max(&self) -> &T
+    where
+        T: Ord,
+    {
+        self.0.iter().max().expect("NonEmptyVec is never empty")
+    }
+
+    /// Appends an element to the back.
+    pub fn push(&mut self, value: T) {
+        self.0.push(value);
+    }
+
+    /// Returns the inner `Vec<T>`.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
+    type Error = EmptyError;
+
+    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            Err(EmptyError)
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for Vec<T> {
+    fn from(value: NonEmptyVec<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T> Deref for NonEmptyVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<[T]> for NonEmptyVec<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> IntoIterator for NonEmptyVec<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyVec<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T> IsEmpty for NonEmptyVec<T> {
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+into_non_empty!(Vec<T> => NonEmptyVec<T>; T);
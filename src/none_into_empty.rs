@@ -0,0 +1,85 @@
+use super::empty::Empty;
+
+/// Import this trait to add the `none_into_empty()` method to `Option<T>` and
+/// `Result<Option<T>, E>`, where `T` implements [`Empty`].
+///
+/// This is the inverse of [`EmptyIntoNone`].
+///
+/// [`Empty`]: crate::empty::Empty
+/// [`EmptyIntoNone`]: crate::empty_into_none::EmptyIntoNone
+pub trait NoneIntoEmpty {
+    /// The type returned by [`none_into_empty`](Self::none_into_empty).
+    type Output;
+
+    /// If the value is `Option::None`, returns an empty value. Otherwise the
+    /// inner value is returned.
+    ///
+    /// For `Result<Option<T>, E>`, the same is done to the `Ok` value, and
+    /// `Err` remains unchanged.
+    ///
+    /// # Examples
+    ///
+    /// These examples only show `Vec<T>`, but any `T` can be used where `T`
+    /// implements [`Empty`].
+    ///
+    /// `None` becomes an empty `Vec`.
+    /// ```
+    /// # use optempty::NoneIntoEmpty;
+    /// #
+    /// let none: Option<Vec<&str>> = None;
+    /// let empty = none.none_into_empty();
+    /// assert_eq!(Vec::<&str>::new(), empty);
+    /// ```
+    ///
+    /// `Some` is unwrapped.
+    /// ```
+    /// # use optempty::NoneIntoEmpty;
+    /// #
+    /// let some = Some(vec!["a", "b", "c"]);
+    /// let v = some.none_into_empty();
+    /// assert_eq!(vec!["a", "b", "c"], v);
+    /// ```
+    ///
+    /// `Ok(None)` becomes `Ok` with an empty `Vec`.
+    /// ```
+    /// # use optempty::NoneIntoEmpty;
+    /// #
+    /// let ok_none: Result<Option<Vec<&str>>, &str> = Ok(None);
+    /// let ok_empty = ok_none.none_into_empty();
+    /// assert_eq!(Ok(vec![]), ok_empty);
+    /// ```
+    ///
+    /// `Err` remains unchanged.
+    /// ```
+    /// # use optempty::NoneIntoEmpty;
+    /// #
+    /// let err: Result<Option<Vec<&str>>, &str> = Err("failed");
+    /// let still_err = err.none_into_empty();
+    /// assert_eq!(Err("failed"), still_err);
+    /// ```
+    ///
+    /// [`Empty`]: crate::empty::Empty
+    fn none_into_empty(self) -> Self::Output;
+}
+
+impl<T> NoneIntoEmpty for Option<T>
+where
+    T: Empty,
+{
+    type Output = T;
+
+    fn none_into_empty(self) -> Self::Output {
+        self.unwrap_or_else(T::empty)
+    }
+}
+
+impl<T, E> NoneIntoEmpty for Result<Option<T>, E>
+where
+    T: Empty,
+{
+    type Output = Result<T, E>;
+
+    fn none_into_empty(self) -> Self::Output {
+        self.map(NoneIntoEmpty::none_into_empty)
+    }
+}
@@ -0,0 +1,92 @@
+use core::str::FromStr;
+
+use super::{empty_into_none::EmptyIntoNone, trim_into_none::TrimIntoNone};
+
+/// Import this trait to add the `parse_non_empty()` and `parse_non_blank()`
+/// methods to `str` (and so `String`), and to `Option<S>` where `S` is a
+/// string.
+///
+/// Empty input parses as `Ok(None)`, rather than the error most [`FromStr`]
+/// impls give for `""`.
+///
+/// [`FromStr`]: core::str::FromStr
+pub trait ParseNonEmpty {
+    /// Returns `Ok(None)` if the value is empty (or `None`), otherwise parses
+    /// it with [`str::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optempty::ParseNonEmpty;
+    ///
+    /// assert_eq!(Ok(None), "".parse_non_empty::<u32>());
+    /// assert_eq!(Ok(Some(42)), "42".parse_non_empty::<u32>());
+    /// assert!(" ".parse_non_empty::<u32>().is_err());
+    ///
+    /// let cell: Option<&str> = None;
+    /// assert_eq!(Ok(None), cell.parse_non_empty::<u32>());
+    /// ```
+    fn parse_non_empty<T>(&self) -> Result<Option<T>, T::Err>
+    where
+        T: FromStr;
+
+    /// Returns `Ok(None)` if the value is empty, only whitespace, or `None`.
+    /// Otherwise, parses it with [`str::parse`] after trimming whitespace from
+    /// both ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optempty::ParseNonEmpty;
+    ///
+    /// assert_eq!(Ok(None), " \t".parse_non_blank::<u32>());
+    /// assert_eq!(Ok(Some(42)), " 42 ".parse_non_blank::<u32>());
+    ///
+    /// let cell = Some(String::from("4 2"));
+    /// assert!(cell.parse_non_blank::<u32>().is_err());
+    /// ```
+    fn parse_non_blank<T>(&self) -> Result<Option<T>, T::Err>
+    where
+        T: FromStr;
+}
+
+impl ParseNonEmpty for str {
+    fn parse_non_empty<T>(&self) -> Result<Option<T>, T::Err>
+    where
+        T: FromStr,
+    {
+        Some(self).empty_into_none().map(str::parse).transpose()
+    }
+
+    fn parse_non_blank<T>(&self) -> Result<Option<T>, T::Err>
+    where
+        T: FromStr,
+    {
+        self.trim_into_none().map(str::parse).transpose()
+    }
+}
+
+impl<S> ParseNonEmpty for Option<S>
+where
+    S: AsRef<str>,
+{
+    fn parse_non_empty<T>(&self) -> Result<Option<T>, T::Err>
+    where
+        T: FromStr,
+    {
+        match self {
+            Some(s) => s.as_ref().parse_non_empty(),
+            None => Ok(None),
+        }
+    }
+
+    fn parse_non_blank<T>(&self) -> Result<Option<T>, T::Err>
+    where
+        T: FromStr,
+    {
+        match self {
+            Some(s) => s.as_ref().parse_non_blank(),
+            None => Ok(None),
+        }
+    }
+}
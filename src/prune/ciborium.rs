@@ -0,0 +1,62 @@
+use ciborium::Value;
+
+use super::{Pointer, PruneEmpty, PruneOptions};
+use crate::is_empty::IsEmpty;
+
+impl PruneEmpty for Value {
+    fn prune_empty_with(&mut self, options: &PruneOptions) {
+        prune_value(self, options, &mut Pointer::root());
+    }
+}
+
+fn prune_value(value: &mut Value, options: &PruneOptions, pointer: &mut Pointer) {
+    if options.keeps_path(pointer) {
+        return;
+    }
+
+    match value {
+        Value::Array(items) => {
+            let mut index = 0;
+            items.retain_mut(|item| {
+                let keep = pointer.with(options, index, |pointer| {
+                    options.keeps_path(pointer) || {
+                        prune_value(item, options, pointer);
+                        !is_prunable(item, options)
+                    }
+                });
+                index += 1;
+                keep
+            });
+        }
+        Value::Map(entries) => {
+            entries.retain_mut(|(key, value)| {
+                let mut entry = |pointer: &mut Pointer| {
+                    options.keeps_path(pointer) || {
+                        prune_value(value, options, pointer);
+                        !is_prunable(value, options)
+                    }
+                };
+
+                match key {
+                    Value::Text(key) => pointer.with(options, key, entry),
+                    Value::Integer(key) => pointer.with(options, i128::from(*key), entry),
+                    Value::Bool(key) => pointer.with(options, key, entry),
+                    _ => pointer.with_unaddressable(|pointer| entry(pointer)),
+                }
+            });
+        }
+        // The tag stays, even if the value inside of it ends up empty.
+        Value::Tag(_, value) => prune_value(value, options, pointer),
+        _ => {}
+    }
+}
+
+/// Returns `true` if an (already pruned) value should be removed from its
+/// parent.
+fn is_prunable(value: &Value, options: &PruneOptions) -> bool {
+    match value {
+        Value::Null => options.prunes_null(),
+        Value::Array(items) => items.is_empty() && options.prunes_empty_arrays(),
+        _ => value.is_empty(),
+    }
+}
@@ -0,0 +1,207 @@
+//! Recursively removing empty values from trees of values, like JSON, TOML,
+//! YAML, or CBOR documents.
+//!
+//! Available with any of the `serdejson`, `toml`, `serde_yaml`, or `ciborium`
+//! features, for the value types of the matching crate.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "serdejson")]
+//! # {
+//! use optempty::prune::PruneEmpty;
+//! use serde_json::json;
+//!
+//! let mut doc = json!({
+//!     "name": "Zoe",
+//!     "nickname": "",
+//!     "tags": [],
+//!     "address": {
+//!         "street": null,
+//!         "lines": [""],
+//!     },
+//! });
+//! doc.prune_empty();
+//! assert_eq!(json!({"name": "Zoe"}), doc);
+//! # }
+//! ```
+
+extern crate alloc;
+use alloc::{collections::BTreeSet, string::String};
+
+#[cfg(feature = "ciborium")]
+mod ciborium;
+#[cfg(feature = "serdejson")]
+mod serde_json;
+#[cfg(feature = "serde_yaml")]
+mod serde_yaml;
+#[cfg(feature = "toml")]
+mod toml;
+
+/// Options for [`PruneEmpty::prune_empty_with`].
+///
+/// By default, every empty value is removed.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "serdejson")]
+/// # {
+/// use optempty::prune::{PruneEmpty, PruneOptions};
+/// use serde_json::json;
+///
+/// let mut doc = json!({
+///     "a": null,
+///     "b": [],
+///     "c": {"d": "", "e": ""},
+///     "f": "",
+/// });
+/// doc.prune_empty_with(
+///     &PruneOptions::new()
+///         .keep_null(true)
+///         .keep_empty_arrays(true)
+///         .keep_path("/c/d"),
+/// );
+/// assert_eq!(json!({"a": null, "b": [], "c": {"d": ""}}), doc);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PruneOptions {
+    keep_null: bool,
+    keep_empty_arrays: bool,
+    keep_paths: BTreeSet<String>,
+}
+
+impl PruneOptions {
+    /// The default options, which remove every empty value.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// If `true`, nulls are not removed. Defaults to `false`.
+    pub fn keep_null(mut self, keep: bool) -> Self {
+        self.keep_null = keep;
+        self
+    }
+
+    /// If `true`, empty arrays (or sequences) are not removed, including ones
+    /// that only became empty by being pruned. Defaults to `false`.
+    pub fn keep_empty_arrays(mut self, keep: bool) -> Self {
+        self.keep_empty_arrays = keep;
+        self
+    }
+
+    /// Keeps the value at `pointer`, a [JSON pointer], as-is. It isn't
+    /// removed, and nothing inside of it is pruned.
+    ///
+    /// Array indexes in the pointer refer to positions in the value before it
+    /// was pruned.
+    ///
+    /// The same syntax is used for TOML, YAML, and CBOR values. For YAML and
+    /// CBOR, map keys that are strings, integers (or YAML numbers), or
+    /// booleans are written as their text. Values under any other kind of key
+    /// can't be kept by path.
+    ///
+    /// May be called more than once to keep more than one path.
+    ///
+    /// [JSON pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+    pub fn keep_path(mut self, pointer: impl Into<String>) -> Self {
+        self.keep_paths.insert(pointer.into());
+        self
+    }
+
+    /// Whether a null should be removed.
+    #[cfg(any(feature = "serdejson", feature = "serde_yaml", feature = "ciborium"))]
+    pub(crate) fn prunes_null(&self) -> bool {
+        !self.keep_null
+    }
+
+    /// Whether an empty array should be removed.
+    pub(crate) fn prunes_empty_arrays(&self) -> bool {
+        !self.keep_empty_arrays
+    }
+
+    /// Returns `true` if the value at `pointer` should be left alone.
+    pub(crate) fn keeps_path(&self, pointer: &Pointer) -> bool {
+        pointer.addressable && self.keep_paths.contains(&pointer.path)
+    }
+
+    /// Returns `true` if paths need to be tracked while pruning.
+    fn tracks_paths(&self) -> bool {
+        !self.keep_paths.is_empty()
+    }
+}
+
+/// Import this trait to add the `prune_empty()` and `prune_empty_with()`
+/// methods to trees of values, such as `serde_json::Value`, `toml::Value`,
+/// `serde_yaml::Value`, and `ciborium::Value`.
+pub trait PruneEmpty {
+    /// Recursively removes empty members from objects and arrays, bottom-up,
+    /// so that ones which become empty from pruning are removed as well.
+    ///
+    /// The value itself is never removed, but may be empty afterward.
+    fn prune_empty(&mut self) {
+        self.prune_empty_with(&PruneOptions::default());
+    }
+
+    /// Like [`prune_empty`](Self::prune_empty), with options.
+    fn prune_empty_with(&mut self, options: &PruneOptions);
+}
+
+/// The JSON pointer to the value currently being pruned. Only built up when
+/// the options ask to keep some paths.
+pub(crate) struct Pointer {
+    path: String,
+    /// `false` under a map key that can't be written as a pointer token, such
+    /// as a YAML sequence. Nothing there can be kept by path.
+    addressable: bool,
+}
+
+impl Pointer {
+    pub(crate) fn root() -> Self {
+        Self {
+            path: String::new(),
+            addressable: true,
+        }
+    }
+
+    /// Calls `f` with the pointer extended by `token`, if paths are being
+    /// tracked.
+    pub(crate) fn with<T>(
+        &mut self,
+        options: &PruneOptions,
+        token: impl core::fmt::Display,
+        f: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        use core::fmt::Write;
+
+        if !options.tracks_paths() {
+            return f(self);
+        }
+
+        let len = self.path.len();
+        self.path.push('/');
+        let start = self.path.len();
+        write!(self.path, "{token}").expect("writing to a String can't fail");
+        // Most tokens have nothing to escape, so only those that do allocate.
+        if self.path[start..].contains(['~', '/']) {
+            let escaped = self.path[start..].replace('~', "~0").replace('/', "~1");
+            self.path.truncate(start);
+            self.path.push_str(&escaped);
+        }
+
+        let result = f(self);
+        self.path.truncate(len);
+        result
+    }
+
+    /// Calls `f` with a pointer that no kept path matches, for values under a
+    /// map key that can't be written as a pointer token.
+    #[cfg(any(feature = "serde_yaml", feature = "ciborium"))]
+    pub(crate) fn with_unaddressable<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        let addressable = core::mem::replace(&mut self.addressable, false);
+        let result = f(self);
+        self.addressable = addressable;
+        result
+    }
+}
@@ -0,0 +1,68 @@
+extern crate alloc;
+use alloc::string::String;
+
+use serde_json::{Map, Value};
+
+use super::{Pointer, PruneEmpty, PruneOptions};
+use crate::is_empty::IsEmpty;
+
+impl PruneEmpty for Value {
+    fn prune_empty_with(&mut self, options: &PruneOptions) {
+        prune_value(self, options, &mut Pointer::root());
+    }
+}
+
+impl PruneEmpty for Map<String, Value> {
+    fn prune_empty_with(&mut self, options: &PruneOptions) {
+        prune_map(self, options, &mut Pointer::root());
+    }
+}
+
+fn prune_value(value: &mut Value, options: &PruneOptions, pointer: &mut Pointer) {
+    if options.keeps_path(pointer) {
+        return;
+    }
+
+    match value {
+        Value::Array(items) => {
+            let mut index = 0;
+            items.retain_mut(|item| {
+                let keep = pointer.with(options, index, |pointer| {
+                    options.keeps_path(pointer) || {
+                        prune_value(item, options, pointer);
+                        !is_prunable(item, options)
+                    }
+                });
+                index += 1;
+                keep
+            });
+        }
+        Value::Object(map) => prune_map(map, options, pointer),
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {}
+    }
+}
+
+fn prune_map(map: &mut Map<String, Value>, options: &PruneOptions, pointer: &mut Pointer) {
+    if options.keeps_path(pointer) {
+        return;
+    }
+
+    map.retain(|key, value| {
+        pointer.with(options, key, |pointer| {
+            options.keeps_path(pointer) || {
+                prune_value(value, options, pointer);
+                !is_prunable(value, options)
+            }
+        })
+    });
+}
+
+/// Returns `true` if an (already pruned) value should be removed from its
+/// parent.
+fn is_prunable(value: &Value, options: &PruneOptions) -> bool {
+    match value {
+        Value::Null => options.prunes_null(),
+        Value::Array(items) => items.is_empty() && options.prunes_empty_arrays(),
+        _ => value.is_empty(),
+    }
+}
@@ -0,0 +1,68 @@
+use toml::{Table, Value};
+
+use super::{Pointer, PruneEmpty, PruneOptions};
+use crate::is_empty::IsEmpty;
+
+impl PruneEmpty for Value {
+    fn prune_empty_with(&mut self, options: &PruneOptions) {
+        prune_value(self, options, &mut Pointer::root());
+    }
+}
+
+impl PruneEmpty for Table {
+    fn prune_empty_with(&mut self, options: &PruneOptions) {
+        prune_table(self, options, &mut Pointer::root());
+    }
+}
+
+fn prune_value(value: &mut Value, options: &PruneOptions, pointer: &mut Pointer) {
+    if options.keeps_path(pointer) {
+        return;
+    }
+
+    match value {
+        Value::Array(items) => {
+            let mut index = 0;
+            items.retain_mut(|item| {
+                let keep = pointer.with(options, index, |pointer| {
+                    options.keeps_path(pointer) || {
+                        prune_value(item, options, pointer);
+                        !is_prunable(item, options)
+                    }
+                });
+                index += 1;
+                keep
+            });
+        }
+        Value::Table(table) => prune_table(table, options, pointer),
+        Value::String(_)
+        | Value::Integer(_)
+        | Value::Float(_)
+        | Value::Boolean(_)
+        | Value::Datetime(_) => {}
+    }
+}
+
+fn prune_table(table: &mut Table, options: &PruneOptions, pointer: &mut Pointer) {
+    if options.keeps_path(pointer) {
+        return;
+    }
+
+    table.retain(|key, value| {
+        pointer.with(options, key, |pointer| {
+            options.keeps_path(pointer) || {
+                prune_value(value, options, pointer);
+                !is_prunable(value, options)
+            }
+        })
+    });
+}
+
+/// Returns `true` if an (already pruned) value should be removed from its
+/// parent. TOML has no null.
+fn is_prunable(value: &Value, options: &PruneOptions) -> bool {
+    match value {
+        Value::Array(items) => items.is_empty() && options.prunes_empty_arrays(),
+        _ => value.is_empty(),
+    }
+}
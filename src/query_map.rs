@@ -0,0 +1,147 @@
+//! Treating empty query parameters, like the `name` in `?name=`, as missing.
+//!
+//! # Examples
+//!
+//! ```
+//! use optempty::query_map::QueryMapNonEmpty;
+//! use query_map::QueryMap;
+//!
+//! let query: QueryMap = "name=&tag=&tag=a&page=2".parse().unwrap();
+//!
+//! assert_eq!(Some(""), query.first("name"));
+//! assert_eq!(None, query.first_non_empty("name"));
+//! assert_eq!(Some(vec!["a"]), query.all_non_empty("tag"));
+//!
+//! let query = query.without_empty();
+//! assert_eq!(None, query.first("name"));
+//! assert_eq!(Some(vec!["a"]), query.all("tag"));
+//! ```
+
+use std::collections::HashMap;
+
+use query_map::QueryMap;
+
+/// Import this trait to add methods to [`QueryMap`] that skip empty values.
+///
+/// A parameter with an empty value, such as `name` in `?name=`, is treated the
+/// same as if it wasn't there at all.
+///
+/// [`QueryMap`]: query_map::QueryMap
+pub trait QueryMapNonEmpty {
+    /// Returns the first non-empty value for `key`, or `None` if there isn't
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optempty::query_map::QueryMapNonEmpty;
+    /// use query_map::QueryMap;
+    ///
+    /// let query: QueryMap = "a=&a=1&b=".parse().unwrap();
+    /// assert_eq!(Some("1"), query.first_non_empty("a"));
+    /// assert_eq!(None, query.first_non_empty("b"));
+    /// assert_eq!(None, query.first_non_empty("c"));
+    /// ```
+    fn first_non_empty(&self, key: &str) -> Option<&str>;
+
+    /// Returns all non-empty values for `key`, or `None` if there aren't any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optempty::query_map::QueryMapNonEmpty;
+    /// use query_map::QueryMap;
+    ///
+    /// let query: QueryMap = "a=1&a=&a=2&b=&b=".parse().unwrap();
+    /// assert_eq!(Some(vec!["1", "2"]), query.all_non_empty("a"));
+    /// assert_eq!(None, query.all_non_empty("b"));
+    /// ```
+    fn all_non_empty(&self, key: &str) -> Option<Vec<&str>>;
+
+    /// Returns a copy without any empty values. Parameters left with no values
+    /// are removed.
+    ///
+    /// # Panics
+    ///
+    /// Like [`QueryMap::iter`], panics if a key has no values at all. That
+    /// can only happen with a `QueryMap` made from a
+    /// `HashMap<String, Vec<String>>` with an empty `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optempty::{query_map::QueryMapNonEmpty, IsEmpty};
+    /// use query_map::QueryMap;
+    ///
+    /// let query: QueryMap = "a=&a=1&b=".parse().unwrap();
+    /// let query = query.without_empty();
+    /// assert_eq!(Some(vec!["1"]), query.all("a"));
+    /// assert_eq!(None, query.all("b"));
+    ///
+    /// let query: QueryMap = "a=&b=".parse().unwrap();
+    /// assert!(IsEmpty::is_empty(&query.without_empty()));
+    /// ```
+    ///
+    /// [`QueryMap::iter`]: query_map::QueryMap::iter
+    fn without_empty(&self) -> QueryMap;
+
+    /// Returns a URL-encoded query string, without any parameters that have
+    /// empty values.
+    ///
+    /// The order of the parameters is unspecified, the same as
+    /// [`QueryMap::to_query_string`].
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same condition as
+    /// [`without_empty`](Self::without_empty).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optempty::query_map::QueryMapNonEmpty;
+    /// use query_map::QueryMap;
+    ///
+    /// let query: QueryMap = "name=&tag=a+b&tag=".parse().unwrap();
+    /// assert_eq!("tag=a+b", query.to_non_empty_query_string());
+    /// ```
+    ///
+    /// [`QueryMap::to_query_string`]: query_map::QueryMap::to_query_string
+    fn to_non_empty_query_string(&self) -> String;
+}
+
+impl QueryMapNonEmpty for QueryMap {
+    fn first_non_empty(&self, key: &str) -> Option<&str> {
+        self.all(key)?.into_iter().find(|v| !v.is_empty())
+    }
+
+    fn all_non_empty(&self, key: &str) -> Option<Vec<&str>> {
+        let values: Vec<&str> = self
+            .all(key)?
+            .into_iter()
+            .filter(|v| !v.is_empty())
+            .collect();
+
+        if values.is_empty() {
+            None
+        } else {
+            Some(values)
+        }
+    }
+
+    fn without_empty(&self) -> QueryMap {
+        let mut map: HashMap<String, Vec<String>> = HashMap::new();
+
+        for (key, value) in self.iter().filter(|(_, v)| !v.is_empty()) {
+            map.entry(key.to_owned())
+                .or_default()
+                .push(value.to_owned());
+        }
+
+        QueryMap::from(map)
+    }
+
+    fn to_non_empty_query_string(&self) -> String {
+        self.without_empty().to_query_string()
+    }
+}
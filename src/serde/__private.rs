@@ -0,0 +1,55 @@
+//! Used by the code generated by `#[omit_empty]`. Not public API.
+
+pub use serde::Serialize;
+
+use crate::is_empty::IsEmpty;
+
+/// Wraps a field's value. `(&&&Probe(value)).__optempty_is_empty()` uses the
+/// value's `IsEmpty` impl when it has one. Otherwise, it checks how the value
+/// serializes, as `OmitEmpty` does, and is `false` if it can't be serialized.
+pub struct Probe<'a, T: ?Sized>(pub &'a T);
+
+pub trait IsEmptyProbe {
+    fn __optempty_is_empty(&self) -> bool;
+}
+
+impl<T> IsEmptyProbe for &&Probe<'_, T>
+where
+    T: IsEmpty + ?Sized,
+{
+    fn __optempty_is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Only picked when `IsEmptyProbe` isn't implemented, since it needs one less
+/// level of reference.
+pub trait SerializeProbe {
+    fn __optempty_is_empty(&self) -> bool;
+}
+
+impl<T> SerializeProbe for &Probe<'_, T>
+where
+    T: Serialize + ?Sized,
+{
+    fn __optempty_is_empty(&self) -> bool {
+        // The helper isn't given the serializer, so this can't ask it. The
+        // few types that serialize differently for other formats aren't empty
+        // either way.
+        super::ser::probe::is_empty(self.0, true)
+    }
+}
+
+/// Only picked when neither of the others is implemented.
+pub trait NotEmptyProbe {
+    fn __optempty_is_empty(&self) -> bool;
+}
+
+impl<T> NotEmptyProbe for Probe<'_, T>
+where
+    T: ?Sized,
+{
+    fn __optempty_is_empty(&self) -> bool {
+        false
+    }
+}
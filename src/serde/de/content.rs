@@ -0,0 +1,690 @@
+extern crate alloc;
+
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+use core::{fmt, marker::PhantomData};
+
+use serde::de::{
+    self,
+    value::{BorrowedStrDeserializer, MapDeserializer, SeqDeserializer, StringDeserializer},
+    Deserialize, DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+
+/// A buffered value, used to hold a map entry's key and value while deciding
+/// whether to leave the entry out.
+///
+/// Borrowed strings and bytes stay borrowed.
+#[derive(Debug)]
+pub(crate) enum Content<'de> {
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    I128(i128),
+    U128(u128),
+    F64(f64),
+    Char(char),
+    String(String),
+    Str(&'de str),
+    ByteBuf(Vec<u8>),
+    Bytes(&'de [u8]),
+    None,
+    Some(Box<Content<'de>>),
+    Unit,
+    Newtype(Box<Content<'de>>),
+    Seq(Vec<Content<'de>>),
+    Map(Vec<(Content<'de>, Content<'de>)>),
+    /// A variant and its value, from formats with their own syntax for enums,
+    /// like YAML's `!Variant value`.
+    Enum(Box<Content<'de>>, Box<Content<'de>>),
+}
+
+impl Content<'_> {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Content::String(s) => Some(s),
+            Content::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Content<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ContentVisitor)
+    }
+}
+
+struct ContentVisitor;
+
+impl<'de> Visitor<'de> for ContentVisitor {
+    type Value = Content<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(Content::Bool(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Content::I64(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Content::U64(v))
+    }
+
+    fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E> {
+        Ok(Content::I128(v))
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(Content::U128(v))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Content::F64(v))
+    }
+
+    fn visit_char<E>(self, v: char) -> Result<Self::Value, E> {
+        Ok(Content::Char(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Content::String(v.to_owned()))
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E> {
+        Ok(Content::Str(v))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+        Ok(Content::String(v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(Content::ByteBuf(v.to_owned()))
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        Ok(Content::Bytes(v))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(Content::ByteBuf(v))
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(Content::None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Content::deserialize(deserializer).map(|c| Content::Some(Box::new(c)))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(Content::Unit)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Content::deserialize(deserializer).map(|c| Content::Newtype(Box::new(c)))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(256));
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Content::Seq(items))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0).min(256));
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(Content::Map(entries))
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (variant, value) = data.variant::<Content<'de>>()?;
+        let value = value.newtype_variant::<Content<'de>>()?;
+        Ok(Content::Enum(Box::new(variant), Box::new(value)))
+    }
+}
+
+/// A buffered map value, and whether it was empty as it was read.
+///
+/// Entries with empty values are left out of any maps inside the value, but
+/// that doesn't make the value itself empty. `{"a": ""}` isn't empty, even
+/// though it's buffered as `{}`.
+pub(crate) struct Buffered<'de> {
+    pub(crate) content: Content<'de>,
+    pub(crate) empty: bool,
+}
+
+impl<'de> Buffered<'de> {
+    fn new(content: Content<'de>, empty: bool) -> Self {
+        Buffered { content, empty }
+    }
+}
+
+/// Buffers a value as [`Buffered`], leaving out map entries with empty values
+/// at every level.
+pub(crate) struct OmitEmptySeed;
+
+impl<'de> DeserializeSeed<'de> for OmitEmptySeed {
+    type Value = Buffered<'de>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(OmitEmptyVisitor)
+    }
+}
+
+struct OmitEmptyVisitor;
+
+// Buffers a value that is never empty.
+macro_rules! visit_not_empty {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                ContentVisitor.$method(v).map(|c| Buffered::new(c, false))
+            }
+        )*
+    };
+}
+
+// Buffers a string or bytes, which are empty if they have no length.
+macro_rules! visit_slice {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                let empty = v.is_empty();
+                ContentVisitor.$method(v).map(|c| Buffered::new(c, empty))
+            }
+        )*
+    };
+}
+
+impl<'de> Visitor<'de> for OmitEmptyVisitor {
+    type Value = Buffered<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    visit_not_empty! {
+        visit_bool(bool);
+        visit_i64(i64);
+        visit_u64(u64);
+        visit_i128(i128);
+        visit_u128(u128);
+        visit_f64(f64);
+        visit_char(char);
+    }
+
+    visit_slice! {
+        visit_str(&str);
+        visit_borrowed_str(&'de str);
+        visit_string(String);
+        visit_bytes(&[u8]);
+        visit_borrowed_bytes(&'de [u8]);
+        visit_byte_buf(Vec<u8>);
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(Buffered::new(Content::None, true))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let inner = OmitEmptySeed.deserialize(deserializer)?;
+        Ok(Buffered::new(
+            Content::Some(Box::new(inner.content)),
+            inner.empty,
+        ))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(Buffered::new(Content::Unit, true))
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let inner = OmitEmptySeed.deserialize(deserializer)?;
+        Ok(Buffered::new(
+            Content::Newtype(Box::new(inner.content)),
+            inner.empty,
+        ))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(256));
+        while let Some(item) = seq.next_element_seed(OmitEmptySeed)? {
+            items.push(item.content);
+        }
+        let empty = items.is_empty();
+        Ok(Buffered::new(Content::Seq(items), empty))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0).min(256));
+        let mut empty = true;
+        while let Some(key) = map.next_key::<Content<'de>>()? {
+            let value = map.next_value_seed(OmitEmptySeed)?;
+            empty = false;
+            if !value.empty {
+                entries.push((key, value.content));
+            }
+        }
+        Ok(Buffered::new(Content::Map(entries), empty))
+    }
+
+    // The variant is read as a newtype variant, which self-describing formats
+    // allow for any variant, so that its value can be buffered as it is. An
+    // enum value is never empty, whatever the variant holds.
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (variant, value) = data.variant::<Content<'de>>()?;
+        let value = value.newtype_variant_seed(OmitEmptySeed)?;
+        Ok(Buffered::new(
+            Content::Enum(Box::new(variant), Box::new(value.content)),
+            false,
+        ))
+    }
+}
+
+/// Replays a buffered [`Content`].
+pub(crate) struct ContentDeserializer<'de, E> {
+    content: Content<'de>,
+    human_readable: bool,
+    marker: PhantomData<E>,
+}
+
+impl<'de, E> ContentDeserializer<'de, E> {
+    /// `human_readable` should be that of the deserializer the content was
+    /// read from.
+    pub(crate) fn new(content: Content<'de>, human_readable: bool) -> Self {
+        ContentDeserializer {
+            content,
+            human_readable,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// An element of a buffered sequence, or a value of a buffered map.
+struct Element<'de> {
+    content: Content<'de>,
+    human_readable: bool,
+}
+
+impl<'de, E> IntoDeserializer<'de, E> for Element<'de>
+where
+    E: de::Error,
+{
+    type Deserializer = ContentDeserializer<'de, E>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        ContentDeserializer::new(self.content, self.human_readable)
+    }
+}
+
+/// A key of a buffered map.
+struct Key<'de> {
+    content: Content<'de>,
+    human_readable: bool,
+}
+
+impl<'de, E> IntoDeserializer<'de, E> for Key<'de>
+where
+    E: de::Error,
+{
+    type Deserializer = MapKeyDeserializer<'de, E>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        MapKeyDeserializer::new(self.content, self.human_readable)
+    }
+}
+
+fn seq<'de, E>(
+    items: Vec<Content<'de>>,
+    human_readable: bool,
+) -> SeqDeserializer<impl Iterator<Item = Element<'de>>, E>
+where
+    E: de::Error,
+{
+    SeqDeserializer::new(items.into_iter().map(move |content| Element {
+        content,
+        human_readable,
+    }))
+}
+
+fn map<'de, E>(
+    entries: Vec<(Content<'de>, Content<'de>)>,
+    human_readable: bool,
+) -> MapDeserializer<'de, impl Iterator<Item = (Key<'de>, Element<'de>)>, E>
+where
+    E: de::Error,
+{
+    MapDeserializer::new(entries.into_iter().map(move |(key, value)| {
+        (
+            Key {
+                content: key,
+                human_readable,
+            },
+            Element {
+                content: value,
+                human_readable,
+            },
+        )
+    }))
+}
+
+impl<'de, E> Deserializer<'de> for ContentDeserializer<'de, E>
+where
+    E: de::Error,
+{
+    type Error = E;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        let human_readable = self.human_readable;
+        match self.content {
+            Content::Bool(v) => visitor.visit_bool(v),
+            Content::I64(v) => visitor.visit_i64(v),
+            Content::U64(v) => visitor.visit_u64(v),
+            Content::I128(v) => visitor.visit_i128(v),
+            Content::U128(v) => visitor.visit_u128(v),
+            Content::F64(v) => visitor.visit_f64(v),
+            Content::Char(v) => visitor.visit_char(v),
+            Content::String(v) => visitor.visit_string(v),
+            Content::Str(v) => visitor.visit_borrowed_str(v),
+            Content::ByteBuf(v) => visitor.visit_byte_buf(v),
+            Content::Bytes(v) => visitor.visit_borrowed_bytes(v),
+            Content::None => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some(ContentDeserializer::new(*v, human_readable)),
+            Content::Unit => visitor.visit_unit(),
+            Content::Newtype(v) => {
+                visitor.visit_newtype_struct(ContentDeserializer::new(*v, human_readable))
+            }
+            Content::Seq(v) => {
+                let mut seq = seq(v, human_readable);
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Content::Map(v) => {
+                let mut map = map(v, human_readable);
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+            Content::Enum(variant, value) => visitor.visit_enum(EnumDeserializer {
+                variant: *variant,
+                value: *value,
+                human_readable,
+                marker: PhantomData,
+            }),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        let human_readable = self.human_readable;
+        match self.content {
+            Content::None | Content::Unit => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some(ContentDeserializer::new(*v, human_readable)),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        let human_readable = self.human_readable;
+        match self.content {
+            Content::Newtype(v) => {
+                visitor.visit_newtype_struct(ContentDeserializer::new(*v, human_readable))
+            }
+            _ => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        let human_readable = self.human_readable;
+        match self.content {
+            Content::String(v) => visitor.visit_enum(StringDeserializer::new(v)),
+            Content::Str(v) => visitor.visit_enum(BorrowedStrDeserializer::new(v)),
+            Content::Map(v) if v.len() == 1 => visitor.visit_enum(
+                de::value::MapAccessDeserializer::new(map(v, human_readable)),
+            ),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.human_readable
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+/// Replays a buffered [`Content::Enum`].
+struct EnumDeserializer<'de, E> {
+    variant: Content<'de>,
+    value: Content<'de>,
+    human_readable: bool,
+    marker: PhantomData<E>,
+}
+
+impl<'de, E> EnumAccess<'de> for EnumDeserializer<'de, E>
+where
+    E: de::Error,
+{
+    type Error = E;
+    type Variant = ContentDeserializer<'de, E>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), E>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant =
+            seed.deserialize(ContentDeserializer::new(self.variant, self.human_readable))?;
+        Ok((
+            variant,
+            ContentDeserializer::new(self.value, self.human_readable),
+        ))
+    }
+}
+
+// The buffered value of a variant, which was read as a newtype variant's.
+impl<'de, E> VariantAccess<'de> for ContentDeserializer<'de, E>
+where
+    E: de::Error,
+{
+    type Error = E;
+
+    fn unit_variant(self) -> Result<(), E> {
+        match self.content {
+            Content::Unit | Content::None => Ok(()),
+            _ => Err(de::Error::invalid_type(
+                de::Unexpected::NewtypeVariant,
+                &"unit variant",
+            )),
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, E>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_any(visitor)
+    }
+
+    fn struct_variant<V>(self, _fields: &'static [&'static str], visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_any(visitor)
+    }
+}
+
+/// Replays a buffered map key.
+///
+/// Some formats, like JSON, only have string keys, and parse them as numbers
+/// or `bool`s when asked to. Buffering the key loses that, so it's done here
+/// instead.
+pub(crate) struct MapKeyDeserializer<'de, E>(ContentDeserializer<'de, E>);
+
+impl<'de, E> MapKeyDeserializer<'de, E> {
+    pub(crate) fn new(content: Content<'de>, human_readable: bool) -> Self {
+        MapKeyDeserializer(ContentDeserializer::new(content, human_readable))
+    }
+}
+
+// Parses a string key as `$ty`, falling back to the buffered value as it is.
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident($ty:ty);)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, E>
+            where
+                V: Visitor<'de>,
+            {
+                match self.0.content.as_str().and_then(|s| s.parse::<$ty>().ok()) {
+                    Some(v) => visitor.$visit(v),
+                    None => self.0.$method(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de, E> Deserializer<'de> for MapKeyDeserializer<'de, E>
+where
+    E: de::Error,
+{
+    type Error = E;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_any(visitor)
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool(bool);
+        deserialize_i8 => visit_i8(i8);
+        deserialize_i16 => visit_i16(i16);
+        deserialize_i32 => visit_i32(i32);
+        deserialize_i64 => visit_i64(i64);
+        deserialize_i128 => visit_i128(i128);
+        deserialize_u8 => visit_u8(u8);
+        deserialize_u16 => visit_u16(u16);
+        deserialize_u32 => visit_u32(u32);
+        deserialize_u64 => visit_u64(u64);
+        deserialize_u128 => visit_u128(u128);
+        deserialize_f32 => visit_f32(f32);
+        deserialize_f64 => visit_f64(f64);
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_option(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_newtype_struct(name, visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_enum(name, variants, visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+
+    serde::forward_to_deserialize_any! {
+        char str string bytes byte_buf unit unit_struct seq tuple tuple_struct
+        map struct identifier ignored_any
+    }
+}
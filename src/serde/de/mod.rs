@@ -0,0 +1,445 @@
+//! A [`Deserializer`] adapter that treats empty values as missing.
+//!
+//! [`Deserializer`]: serde::Deserializer
+
+extern crate alloc;
+
+mod content;
+
+use core::fmt;
+
+use serde::de::{
+    DeserializeSeed, Deserializer, EnumAccess, Error, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+
+use self::content::{Content, ContentDeserializer, MapKeyDeserializer, OmitEmptySeed};
+
+/// Wraps a [`Deserializer`] so that map entries with empty values are left
+/// out, as if they weren't in the input at all.
+///
+/// Empty strings, empty byte strings, empty sequences, empty maps, `null`,
+/// unit, and `Some` of any of those, are all empty. Struct fields are map
+/// entries in most formats, so an empty field is missing: an `Option` field is
+/// `None`, a `#[serde(default)]` field gets its default, and any other field
+/// is a "missing field" error. Nested values are wrapped too, so this applies
+/// at every level, not just to the outermost struct. Elements of sequences are
+/// never left out, and neither are enum variants written in a format's own
+/// syntax, like YAML's `!Variant value`.
+///
+/// Whether a value is empty is decided as it is read, before any entries
+/// inside it are left out. `{"a": ""}` isn't empty, so it's kept as `{}`.
+///
+/// Reading a value without knowing its type needs `deserialize_any`, so maps
+/// can only be read from self-describing formats, like JSON, YAML, TOML or
+/// CBOR. Formats that aren't self-describing, like bincode, write structs as
+/// sequences, which are handed on unchanged, but can't read maps through
+/// `OmitEmpty`.
+///
+/// # Limitations
+///
+/// `OmitEmpty` doesn't avoid allocating for large inputs. Every sequence or
+/// map value inside a map entry is copied into memory before it's handed on,
+/// so nested containers are held twice: once buffered, and once in the value
+/// being deserialized.
+///
+/// That's because a map entry's value has to be read before its key is handed
+/// on, to know whether to leave the entry out, and serde only allows a value
+/// to be read all at once. Scalars, and strings and bytes borrowed from the
+/// input, are held without allocating. The outermost value, and elements of
+/// sequences, are handed on as they are read.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use serde::Deserialize;
+/// use optempty::serde::de::OmitEmpty;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct Person {
+///     name: String,
+///     nickname: Option<String>,
+///     #[serde(default = "unknown")]
+///     city: String,
+///     labels: BTreeMap<String, String>,
+/// }
+///
+/// fn unknown() -> String {
+///     "unknown".into()
+/// }
+///
+/// let json = r#"{
+///     "name": "Zoe",
+///     "nickname": "",
+///     "city": "",
+///     "labels": { "team": "core", "floor": "" }
+/// }"#;
+///
+/// let mut de = serde_json::Deserializer::from_str(json);
+/// let person = Person::deserialize(OmitEmpty::new(&mut de)).unwrap();
+/// de.end().unwrap();
+///
+/// assert_eq!(
+///     Person {
+///         name: "Zoe".into(),
+///         nickname: None,
+///         city: "unknown".into(),
+///         labels: BTreeMap::from([("team".into(), "core".into())]),
+///     },
+///     person,
+/// );
+/// ```
+///
+/// [`Deserializer`]: serde::Deserializer
+#[derive(Debug, Clone, Copy)]
+pub struct OmitEmpty<D>(D);
+
+impl<D> OmitEmpty<D> {
+    /// Wraps `deserializer`.
+    pub fn new(deserializer: D) -> Self {
+        OmitEmpty(deserializer)
+    }
+
+    /// Returns the wrapped deserializer.
+    pub fn into_inner(self) -> D {
+        self.0
+    }
+}
+
+// Forwards `deserialize_*` methods to the wrapped deserializer, wrapping the
+// visitor.
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method<V>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                let visitor = Wrap {
+                    visitor,
+                    human_readable: self.0.is_human_readable(),
+                };
+                self.0.$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, D> Deserializer<'de> for OmitEmpty<D>
+where
+    D: Deserializer<'de>,
+{
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any();
+        deserialize_bool();
+        deserialize_i8();
+        deserialize_i16();
+        deserialize_i32();
+        deserialize_i64();
+        deserialize_i128();
+        deserialize_u8();
+        deserialize_u16();
+        deserialize_u32();
+        deserialize_u64();
+        deserialize_u128();
+        deserialize_f32();
+        deserialize_f64();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_bytes();
+        deserialize_byte_buf();
+        deserialize_option();
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_seq();
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map();
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier();
+        deserialize_ignored_any();
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+// Forwards `visit_*` methods that take a single value.
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                self.visitor.$method(v)
+            }
+        )*
+    };
+}
+
+/// Wraps a visitor, so that any deserializers or accessors it is given are
+/// wrapped in turn.
+struct Wrap<V> {
+    visitor: V,
+    human_readable: bool,
+}
+
+impl<'de, V> Visitor<'de> for Wrap<V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.visitor.expecting(f)
+    }
+
+    forward_visit! {
+        visit_bool(bool);
+        visit_i8(i8);
+        visit_i16(i16);
+        visit_i32(i32);
+        visit_i64(i64);
+        visit_i128(i128);
+        visit_u8(u8);
+        visit_u16(u16);
+        visit_u32(u32);
+        visit_u64(u64);
+        visit_u128(u128);
+        visit_f32(f32);
+        visit_f64(f64);
+        visit_char(char);
+        visit_str(&str);
+        visit_borrowed_str(&'de str);
+        visit_string(alloc::string::String);
+        visit_bytes(&[u8]);
+        visit_borrowed_bytes(&'de [u8]);
+        visit_byte_buf(alloc::vec::Vec<u8>);
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visitor.visit_none()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.visitor.visit_some(OmitEmpty(deserializer))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visitor.visit_unit()
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.visitor.visit_newtype_struct(OmitEmpty(deserializer))
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.visitor.visit_seq(Seq(seq))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.visitor.visit_map(Map {
+            map,
+            value: None,
+            human_readable: self.human_readable,
+        })
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        self.visitor.visit_enum(Enum {
+            data,
+            human_readable: self.human_readable,
+        })
+    }
+}
+
+/// Deserializes a seed through [`OmitEmpty`].
+struct Seed<S>(S);
+
+impl<'de, S> DeserializeSeed<'de> for Seed<S>
+where
+    S: DeserializeSeed<'de>,
+{
+    type Value = S::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0.deserialize(OmitEmpty(deserializer))
+    }
+}
+
+struct Seq<A>(A);
+
+impl<'de, A> SeqAccess<'de> for Seq<A>
+where
+    A: SeqAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.0.next_element_seed(Seed(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+/// Leaves out entries with empty values. Each value is read, and held in
+/// `value`, before its key is handed on.
+struct Map<'de, A> {
+    map: A,
+    value: Option<Content<'de>>,
+    human_readable: bool,
+}
+
+impl<'de, A> MapAccess<'de> for Map<'de, A>
+where
+    A: MapAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        while let Some(key) = self.map.next_key::<Content<'de>>()? {
+            let value = self.map.next_value_seed(OmitEmptySeed)?;
+            if value.empty {
+                continue;
+            }
+
+            self.value = Some(value.content);
+            return seed
+                .deserialize(MapKeyDeserializer::new(key, self.human_readable))
+                .map(Some);
+        }
+
+        Ok(None)
+    }
+
+    fn next_value_seed<T>(&mut self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let value = self
+            .value
+            .take()
+            .ok_or_else(|| A::Error::custom("value is missing"))?;
+        seed.deserialize(ContentDeserializer::new(value, self.human_readable))
+    }
+
+    // How many entries will be left out isn't known until they're read.
+}
+
+struct Enum<A> {
+    data: A,
+    human_readable: bool,
+}
+
+impl<'de, A> EnumAccess<'de> for Enum<A>
+where
+    A: EnumAccess<'de>,
+{
+    type Error = A::Error;
+    type Variant = Variant<A::Variant>;
+
+    fn variant_seed<T>(self, seed: T) -> Result<(T::Value, Self::Variant), Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let human_readable = self.human_readable;
+        self.data.variant_seed(Seed(seed)).map(|(value, variant)| {
+            (
+                value,
+                Variant {
+                    variant,
+                    human_readable,
+                },
+            )
+        })
+    }
+}
+
+struct Variant<A> {
+    variant: A,
+    human_readable: bool,
+}
+
+impl<'de, A> VariantAccess<'de> for Variant<A>
+where
+    A: VariantAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.variant.unit_variant()
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.variant.newtype_variant_seed(Seed(seed))
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let visitor = Wrap {
+            visitor,
+            human_readable: self.human_readable,
+        };
+        self.variant.tuple_variant(len, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let visitor = Wrap {
+            visitor,
+            human_readable: self.human_readable,
+        };
+        self.variant.struct_variant(fields, visitor)
+    }
+}
@@ -0,0 +1,55 @@
+//! For use with `#[serde(with = "optempty::serde::empty_as_none")]` on
+//! `Option<T>` fields, where `T` implements [`IsEmpty`].
+//!
+//! When deserializing, an empty value (e.g., `""`, `[]`, or `{}`) becomes
+//! `None`, as does a missing value if the field also has `#[serde(default)]`.
+//!
+//! When serializing, `None` is written as the empty value of `T` (its
+//! `Default`).
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Tags {
+//!     #[serde(default, with = "optempty::serde::empty_as_none")]
+//!     tags: Option<Vec<String>>,
+//! }
+//!
+//! let tags: Tags = serde_json::from_str(r#"{"tags":[]}"#).unwrap();
+//! assert_eq!(Tags { tags: None }, tags);
+//!
+//! let tags: Tags = serde_json::from_str(r#"{}"#).unwrap();
+//! assert_eq!(Tags { tags: None }, tags);
+//!
+//! assert_eq!(r#"{"tags":[]}"#, serde_json::to_string(&tags).unwrap());
+//! ```
+//!
+//! [`IsEmpty`]: crate::is_empty::IsEmpty
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{empty_into_none::EmptyIntoNone, is_empty::IsEmpty};
+
+/// Serializes `Some(value)` as `value`, and `None` as `T::default()`.
+pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize + Default,
+    S: Serializer,
+{
+    match value {
+        Some(value) => serializer.serialize_some(value),
+        None => serializer.serialize_some(&T::default()),
+    }
+}
+
+/// Deserializes an `Option<T>`, turning `Some` with an empty value into `None`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de> + IsEmpty,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(EmptyIntoNone::empty_into_none)
+}
@@ -0,0 +1,77 @@
+//! Helpers for using [`IsEmpty`] with [`serde`].
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Person {
+//!     name: String,
+//!     #[serde(default, skip_serializing_if = "optempty::serde::is_empty")]
+//!     aliases: Vec<String>,
+//!     #[serde(default, with = "optempty::serde::empty_as_none")]
+//!     nickname: Option<String>,
+//! }
+//!
+//! let person: Person = serde_json::from_str(r#"{"name":"Zoe","nickname":""}"#).unwrap();
+//! assert_eq!(
+//!     Person {
+//!         name: "Zoe".into(),
+//!         aliases: vec![],
+//!         nickname: None,
+//!     },
+//!     person,
+//! );
+//! assert_eq!(
+//!     r#"{"name":"Zoe","nickname":""}"#,
+//!     serde_json::to_string(&person).unwrap(),
+//! );
+//! ```
+//!
+//! [`IsEmpty`]: crate::is_empty::IsEmpty
+
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private;
+#[cfg(feature = "alloc")]
+pub mod de;
+pub mod empty_as_none;
+pub mod ser;
+
+pub use self::ser::OmitEmpty;
+
+use crate::is_empty::IsEmpty;
+
+/// Returns `true` if `value` is empty. For use with
+/// `#[serde(skip_serializing_if = "optempty::serde::is_empty")]`.
+///
+/// Works with anything that implements [`IsEmpty`].
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Query {
+///     #[serde(skip_serializing_if = "optempty::serde::is_empty")]
+///     terms: Vec<&'static str>,
+///     #[serde(skip_serializing_if = "optempty::serde::is_empty")]
+///     sort: Option<String>,
+/// }
+///
+/// let query = Query {
+///     terms: vec![],
+///     sort: Some(String::new()),
+/// };
+/// assert_eq!("{}", serde_json::to_string(&query).unwrap());
+/// ```
+///
+/// [`IsEmpty`]: crate::is_empty::IsEmpty
+pub fn is_empty<T>(value: &T) -> bool
+where
+    T: IsEmpty + ?Sized,
+{
+    value.is_empty()
+}
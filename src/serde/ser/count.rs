@@ -0,0 +1,284 @@
+use core::fmt::{self, Display};
+
+use serde::ser::{self, Serialize, SerializeMap, SerializeStruct, SerializeStructVariant};
+
+use super::probe;
+
+/// Returns how many fields or entries will be kept if `value` is a struct,
+/// struct variant or map, so the real length can be given to the serializer
+/// up front. Returns `None` for anything else.
+///
+/// Only the outermost value is counted. Nested values are counted when they
+/// are serialized in turn.
+pub(crate) fn kept<T>(value: &T, human_readable: bool) -> Option<usize>
+where
+    T: Serialize + ?Sized,
+{
+    value.serialize(Counter { human_readable }).ok()
+}
+
+/// Returned for anything that isn't counted.
+#[derive(Debug)]
+pub(crate) struct NotCounted;
+
+impl Display for NotCounted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("value was not a struct or map")
+    }
+}
+
+impl ser::StdError for NotCounted {}
+
+impl ser::Error for NotCounted {
+    fn custom<T>(_msg: T) -> Self
+    where
+        T: Display,
+    {
+        NotCounted
+    }
+}
+
+/// A serializer that produces the number of fields or entries that won't be
+/// left out.
+struct Counter {
+    human_readable: bool,
+}
+
+// Values of these types aren't counted.
+macro_rules! not_counted {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method(self, _v: $ty) -> Result<usize, NotCounted> {
+                Err(NotCounted)
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for Counter {
+    type Ok = usize;
+    type Error = NotCounted;
+    type SerializeSeq = ser::Impossible<usize, NotCounted>;
+    type SerializeTuple = ser::Impossible<usize, NotCounted>;
+    type SerializeTupleStruct = ser::Impossible<usize, NotCounted>;
+    type SerializeTupleVariant = ser::Impossible<usize, NotCounted>;
+    type SerializeMap = Kept;
+    type SerializeStruct = Kept;
+    type SerializeStructVariant = Kept;
+
+    not_counted! {
+        serialize_bool(bool);
+        serialize_i8(i8);
+        serialize_i16(i16);
+        serialize_i32(i32);
+        serialize_i64(i64);
+        serialize_i128(i128);
+        serialize_u8(u8);
+        serialize_u16(u16);
+        serialize_u32(u32);
+        serialize_u64(u64);
+        serialize_u128(u128);
+        serialize_f32(f32);
+        serialize_f64(f64);
+        serialize_char(char);
+        serialize_str(&str);
+        serialize_bytes(&[u8]);
+        serialize_unit_struct(&'static str);
+    }
+
+    fn serialize_none(self) -> Result<usize, NotCounted> {
+        Err(NotCounted)
+    }
+
+    fn serialize_some<T>(self, _value: &T) -> Result<usize, NotCounted>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(NotCounted)
+    }
+
+    fn serialize_unit(self) -> Result<usize, NotCounted> {
+        Err(NotCounted)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<usize, NotCounted> {
+        Err(NotCounted)
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        _value: &T,
+    ) -> Result<usize, NotCounted>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(NotCounted)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<usize, NotCounted>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(NotCounted)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, NotCounted> {
+        Err(NotCounted)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, NotCounted> {
+        Err(NotCounted)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, NotCounted> {
+        Err(NotCounted)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, NotCounted> {
+        Err(NotCounted)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Kept, NotCounted> {
+        Ok(Kept::new(self.human_readable))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Kept, NotCounted> {
+        Ok(Kept::new(self.human_readable))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Kept, NotCounted> {
+        Ok(Kept::new(self.human_readable))
+    }
+
+    fn collect_str<T>(self, _value: &T) -> Result<usize, NotCounted>
+    where
+        T: Display + ?Sized,
+    {
+        Err(NotCounted)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.human_readable
+    }
+}
+
+/// Counts the fields or entries of a struct or map that won't be left out,
+/// using the same checks as `Compound`.
+struct Kept {
+    count: usize,
+    human_readable: bool,
+}
+
+impl Kept {
+    fn new(human_readable: bool) -> Self {
+        Kept {
+            count: 0,
+            human_readable,
+        }
+    }
+
+    fn field<T>(&mut self, value: &T)
+    where
+        T: Serialize + ?Sized,
+    {
+        if !probe::is_empty(value, self.human_readable) {
+            self.count += 1;
+        }
+    }
+}
+
+impl SerializeMap for Kept {
+    type Ok = usize;
+    type Error = NotCounted;
+
+    // Entries serialized as a separate key and value are always kept.
+    fn serialize_key<T>(&mut self, _key: &T) -> Result<(), NotCounted>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.count += 1;
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, _value: &T) -> Result<(), NotCounted>
+    where
+        T: Serialize + ?Sized,
+    {
+        Ok(())
+    }
+
+    fn serialize_entry<K, V>(&mut self, _key: &K, value: &V) -> Result<(), NotCounted>
+    where
+        K: Serialize + ?Sized,
+        V: Serialize + ?Sized,
+    {
+        self.field(value);
+        Ok(())
+    }
+
+    fn end(self) -> Result<usize, NotCounted> {
+        Ok(self.count)
+    }
+}
+
+impl SerializeStruct for Kept {
+    type Ok = usize;
+    type Error = NotCounted;
+
+    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<(), NotCounted>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.field(value);
+        Ok(())
+    }
+
+    fn end(self) -> Result<usize, NotCounted> {
+        Ok(self.count)
+    }
+}
+
+impl SerializeStructVariant for Kept {
+    type Ok = usize;
+    type Error = NotCounted;
+
+    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<(), NotCounted>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.field(value);
+        Ok(())
+    }
+
+    fn end(self) -> Result<usize, NotCounted> {
+        Ok(self.count)
+    }
+}
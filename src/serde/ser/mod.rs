@@ -0,0 +1,462 @@
+//! A [`Serialize`] wrapper that leaves out empty fields.
+//!
+//! [`Serialize`]: serde::Serialize
+
+mod count;
+pub(crate) mod probe;
+
+use core::fmt::Display;
+
+use serde::ser::{
+    Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant, Serializer,
+};
+
+/// Wraps a value so that, when serialized, struct fields and map entries with
+/// empty values are left out, as if every field had
+/// `#[serde(skip_serializing_if = "optempty::serde::is_empty")]`.
+///
+/// This is useful for types that can't be annotated, such as those from other
+/// crates. It works with any [`Serialize`] type, and any serde format that
+/// writes field names, like JSON, YAML, TOML or CBOR. Formats that write a
+/// struct's fields by position, like bincode or postcard, can't tell which
+/// fields were left out, so their output can't be read back.
+///
+/// A value is empty if it serializes as `""`, empty bytes, `None`, unit, or a
+/// sequence, tuple or map with no elements, the same as its [`IsEmpty`] impl
+/// would say. `Some` of an empty value is empty. Structs and enums are never
+/// empty, even if all of their fields are.
+///
+/// Nested values are wrapped too, so this applies at every level. Elements of
+/// sequences are never left out.
+///
+/// Structs and maps are given the number of fields or entries that are kept,
+/// so length-prefixed formats stay valid. To count them, each struct or map is
+/// serialized once up front, checking each field's value for emptiness,
+/// stopping as soon as it is known not to be. Each value is then checked
+/// again, and serialized for real.
+///
+/// Map entries are only left out when the map is serialized with
+/// [`SerializeMap::serialize_entry`], as the standard maps are. A map that
+/// serializes the key and value separately has all of its entries kept.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use serde::Serialize;
+/// use optempty::serde::OmitEmpty;
+///
+/// // Pretend this comes from another crate.
+/// #[derive(Serialize)]
+/// struct Person {
+///     name: String,
+///     nickname: Option<String>,
+///     aliases: Vec<String>,
+///     labels: BTreeMap<&'static str, &'static str>,
+/// }
+///
+/// let person = Person {
+///     name: "Zoe".into(),
+///     nickname: Some(String::new()),
+///     aliases: vec![],
+///     labels: BTreeMap::from([("team", "core"), ("floor", "")]),
+/// };
+///
+/// assert_eq!(
+///     r#"{"name":"Zoe","labels":{"team":"core"}}"#,
+///     serde_json::to_string(&OmitEmpty(&person)).unwrap(),
+/// );
+/// ```
+///
+/// [`IsEmpty`]: crate::is_empty::IsEmpty
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct OmitEmpty<T>(pub T);
+
+impl<T> Serialize for OmitEmpty<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = count::kept(&self.0, serializer.is_human_readable());
+        self.0.serialize(Wrap(serializer, len))
+    }
+}
+
+/// Wraps a serializer, so that the values given to it are wrapped in turn.
+///
+/// The second field is how many fields or entries will be kept, if the value
+/// is a struct or map.
+struct Wrap<S>(S, Option<usize>);
+
+/// Wraps one of a serializer's compound types, such as `SerializeStruct`.
+struct Compound<C> {
+    inner: C,
+    human_readable: bool,
+}
+
+impl<C> Compound<C> {
+    fn is_empty<T>(&self, value: &T) -> bool
+    where
+        T: Serialize + ?Sized,
+    {
+        probe::is_empty(value, self.human_readable)
+    }
+}
+
+// Forwards `serialize_*` methods that take a single value.
+macro_rules! forward_serialize {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<S::Ok, S::Error> {
+                self.0.$method(v)
+            }
+        )*
+    };
+}
+
+impl<S> Serializer for Wrap<S>
+where
+    S: Serializer,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Compound<S::SerializeSeq>;
+    type SerializeTuple = Compound<S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<S::SerializeTupleVariant>;
+    type SerializeMap = Compound<S::SerializeMap>;
+    type SerializeStruct = Compound<S::SerializeStruct>;
+    type SerializeStructVariant = Compound<S::SerializeStructVariant>;
+
+    forward_serialize! {
+        serialize_bool(bool);
+        serialize_i8(i8);
+        serialize_i16(i16);
+        serialize_i32(i32);
+        serialize_i64(i64);
+        serialize_i128(i128);
+        serialize_u8(u8);
+        serialize_u16(u16);
+        serialize_u32(u32);
+        serialize_u64(u64);
+        serialize_u128(u128);
+        serialize_f32(f32);
+        serialize_f64(f64);
+        serialize_char(char);
+        serialize_str(&str);
+        serialize_bytes(&[u8]);
+        serialize_unit_struct(&'static str);
+    }
+
+    fn serialize_none(self) -> Result<S::Ok, S::Error> {
+        self.0.serialize_none()
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.0.serialize_some(&OmitEmpty(value))
+    }
+
+    fn serialize_unit(self) -> Result<S::Ok, S::Error> {
+        self.0.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<S::Ok, S::Error> {
+        self.0.serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.0.serialize_newtype_struct(name, &OmitEmpty(value))
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.0
+            .serialize_newtype_variant(name, variant_index, variant, &OmitEmpty(value))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+        let human_readable = self.0.is_human_readable();
+        Ok(Compound {
+            inner: self.0.serialize_seq(len)?,
+            human_readable,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, S::Error> {
+        let human_readable = self.0.is_human_readable();
+        Ok(Compound {
+            inner: self.0.serialize_tuple(len)?,
+            human_readable,
+        })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, S::Error> {
+        let human_readable = self.0.is_human_readable();
+        Ok(Compound {
+            inner: self.0.serialize_tuple_struct(name, len)?,
+            human_readable,
+        })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, S::Error> {
+        let human_readable = self.0.is_human_readable();
+        Ok(Compound {
+            inner: self
+                .0
+                .serialize_tuple_variant(name, variant_index, variant, len)?,
+            human_readable,
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, S::Error> {
+        let human_readable = self.0.is_human_readable();
+        Ok(Compound {
+            inner: self.0.serialize_map(self.1.or(len))?,
+            human_readable,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        let human_readable = self.0.is_human_readable();
+        Ok(Compound {
+            inner: self.0.serialize_struct(name, self.1.unwrap_or(len))?,
+            human_readable,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, S::Error> {
+        let human_readable = self.0.is_human_readable();
+        Ok(Compound {
+            inner: self.0.serialize_struct_variant(
+                name,
+                variant_index,
+                variant,
+                self.1.unwrap_or(len),
+            )?,
+            human_readable,
+        })
+    }
+
+    fn collect_str<T>(self, value: &T) -> Result<S::Ok, S::Error>
+    where
+        T: Display + ?Sized,
+    {
+        self.0.collect_str(value)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+impl<C> SerializeSeq for Compound<C>
+where
+    C: SerializeSeq,
+{
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), C::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.inner.serialize_element(&OmitEmpty(value))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C> SerializeTuple for Compound<C>
+where
+    C: SerializeTuple,
+{
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), C::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.inner.serialize_element(&OmitEmpty(value))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C> SerializeTupleStruct for Compound<C>
+where
+    C: SerializeTupleStruct,
+{
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), C::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.inner.serialize_field(&OmitEmpty(value))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C> SerializeTupleVariant for Compound<C>
+where
+    C: SerializeTupleVariant,
+{
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), C::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.inner.serialize_field(&OmitEmpty(value))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C> SerializeMap for Compound<C>
+where
+    C: SerializeMap,
+{
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), C::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.inner.serialize_key(key)
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), C::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.inner.serialize_value(&OmitEmpty(value))
+    }
+
+    fn serialize_entry<K, V>(&mut self, key: &K, value: &V) -> Result<(), C::Error>
+    where
+        K: Serialize + ?Sized,
+        V: Serialize + ?Sized,
+    {
+        if self.is_empty(value) {
+            return Ok(());
+        }
+
+        self.inner.serialize_entry(key, &OmitEmpty(value))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C> SerializeStruct for Compound<C>
+where
+    C: SerializeStruct,
+{
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), C::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        if self.is_empty(value) {
+            return self.inner.skip_field(key);
+        }
+
+        self.inner.serialize_field(key, &OmitEmpty(value))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C> SerializeStructVariant for Compound<C>
+where
+    C: SerializeStructVariant,
+{
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), C::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        if self.is_empty(value) {
+            return self.inner.skip_field(key);
+        }
+
+        self.inner.serialize_field(key, &OmitEmpty(value))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
@@ -0,0 +1,278 @@
+use core::fmt::{self, Display, Write};
+
+use serde::ser::{self, Serialize, SerializeMap, SerializeSeq, SerializeTuple};
+
+/// Returns `true` if `value` serializes as an empty value: `""`, empty bytes,
+/// `None` (or `Some` of an empty value), unit, or a sequence, tuple or map
+/// with no elements. Newtype structs are empty if their field is.
+///
+/// Serialization is stopped as soon as the value is known not to be empty, so
+/// this doesn't walk the whole of a large value.
+pub(crate) fn is_empty<T>(value: &T, human_readable: bool) -> bool
+where
+    T: Serialize + ?Sized,
+{
+    value.serialize(Probe { human_readable }).unwrap_or(false)
+}
+
+/// Returned to stop serializing once the value is known not to be empty.
+#[derive(Debug)]
+pub(crate) struct NotEmpty;
+
+impl Display for NotEmpty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("value was not empty")
+    }
+}
+
+impl ser::StdError for NotEmpty {}
+
+impl ser::Error for NotEmpty {
+    fn custom<T>(_msg: T) -> Self
+    where
+        T: Display,
+    {
+        NotEmpty
+    }
+}
+
+/// A serializer that produces `true` for an empty value.
+struct Probe {
+    human_readable: bool,
+}
+
+// Values of these types are never empty.
+macro_rules! not_empty {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method(self, _v: $ty) -> Result<bool, NotEmpty> {
+                Ok(false)
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for Probe {
+    type Ok = bool;
+    type Error = NotEmpty;
+    type SerializeSeq = Elements;
+    type SerializeTuple = Elements;
+    type SerializeTupleStruct = ser::Impossible<bool, NotEmpty>;
+    type SerializeTupleVariant = ser::Impossible<bool, NotEmpty>;
+    type SerializeMap = Elements;
+    type SerializeStruct = ser::Impossible<bool, NotEmpty>;
+    type SerializeStructVariant = ser::Impossible<bool, NotEmpty>;
+
+    not_empty! {
+        serialize_bool(bool);
+        serialize_i8(i8);
+        serialize_i16(i16);
+        serialize_i32(i32);
+        serialize_i64(i64);
+        serialize_i128(i128);
+        serialize_u8(u8);
+        serialize_u16(u16);
+        serialize_u32(u32);
+        serialize_u64(u64);
+        serialize_u128(u128);
+        serialize_f32(f32);
+        serialize_f64(f64);
+        serialize_char(char);
+        serialize_unit_struct(&'static str);
+    }
+
+    fn serialize_str(self, v: &str) -> Result<bool, NotEmpty> {
+        Ok(v.is_empty())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<bool, NotEmpty> {
+        Ok(v.is_empty())
+    }
+
+    fn serialize_none(self) -> Result<bool, NotEmpty> {
+        Ok(true)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<bool, NotEmpty>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<bool, NotEmpty> {
+        Ok(true)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<bool, NotEmpty> {
+        Ok(false)
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<bool, NotEmpty>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<bool, NotEmpty>
+    where
+        T: Serialize + ?Sized,
+    {
+        Ok(false)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Elements, NotEmpty> {
+        Elements::new(len)
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Elements, NotEmpty> {
+        Elements::new(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, NotEmpty> {
+        Err(NotEmpty)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, NotEmpty> {
+        Err(NotEmpty)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Elements, NotEmpty> {
+        Elements::new(len)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, NotEmpty> {
+        Err(NotEmpty)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, NotEmpty> {
+        Err(NotEmpty)
+    }
+
+    fn collect_str<T>(self, value: &T) -> Result<bool, NotEmpty>
+    where
+        T: Display + ?Sized,
+    {
+        let mut empty = IsEmptyWriter(true);
+        // Only errors once something has been written.
+        Ok(write!(empty, "{value}").is_ok() && empty.0)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.human_readable
+    }
+}
+
+/// Stops at the first write of anything other than `""`.
+struct IsEmptyWriter(bool);
+
+impl Write for IsEmptyWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.is_empty() {
+            Ok(())
+        } else {
+            self.0 = false;
+            Err(fmt::Error)
+        }
+    }
+}
+
+/// The elements of a sequence, tuple or map, which is empty if there are
+/// none.
+struct Elements;
+
+impl Elements {
+    fn new(len: Option<usize>) -> Result<Self, NotEmpty> {
+        match len {
+            Some(0) | None => Ok(Elements),
+            Some(_) => Err(NotEmpty),
+        }
+    }
+}
+
+impl SerializeSeq for Elements {
+    type Ok = bool;
+    type Error = NotEmpty;
+
+    fn serialize_element<T>(&mut self, _value: &T) -> Result<(), NotEmpty>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(NotEmpty)
+    }
+
+    fn end(self) -> Result<bool, NotEmpty> {
+        Ok(true)
+    }
+}
+
+impl SerializeTuple for Elements {
+    type Ok = bool;
+    type Error = NotEmpty;
+
+    fn serialize_element<T>(&mut self, _value: &T) -> Result<(), NotEmpty>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(NotEmpty)
+    }
+
+    fn end(self) -> Result<bool, NotEmpty> {
+        Ok(true)
+    }
+}
+
+impl SerializeMap for Elements {
+    type Ok = bool;
+    type Error = NotEmpty;
+
+    fn serialize_key<T>(&mut self, _key: &T) -> Result<(), NotEmpty>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(NotEmpty)
+    }
+
+    fn serialize_value<T>(&mut self, _value: &T) -> Result<(), NotEmpty>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(NotEmpty)
+    }
+
+    fn end(self) -> Result<bool, NotEmpty> {
+        Ok(true)
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+
+use super::is_blank::Whitespace;
+
+/// Import this trait to add the `trim_into_none()` method to `&str`, `String`,
+/// `Cow<'_, str>`, and `Option`s of them.
+///
+/// The value is trimmed of whitespace, and becomes `None` if nothing is left.
+/// A `&str` (or borrowed `Cow`) is trimmed by slicing it, and a `String` (or
+/// owned `Cow`) is trimmed in place, so nothing is reallocated.
+pub trait TrimIntoNone: Sized {
+    /// The type of the trimmed value.
+    type Trimmed;
+
+    /// Returns the value with [`Whitespace::Unicode`] trimmed from both ends,
+    /// or `None` if that leaves it empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optempty::TrimIntoNone;
+    ///
+    /// assert_eq!(Some("a b"), " a b\n".trim_into_none());
+    /// assert_eq!(None, " \t".trim_into_none());
+    ///
+    /// let name = Some(String::from("  Zoe  "));
+    /// assert_eq!(Some(String::from("Zoe")), name.trim_into_none());
+    ///
+    /// let none: Option<&str> = None;
+    /// assert_eq!(None, none.trim_into_none());
+    /// ```
+    ///
+    /// [`Whitespace::Unicode`]: crate::is_blank::Whitespace::Unicode
+    fn trim_into_none(self) -> Option<Self::Trimmed> {
+        self.trim_into_none_with(Whitespace::Unicode)
+    }
+
+    /// Like [`trim_into_none`](Self::trim_into_none), but with the given
+    /// definition of whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optempty::{is_blank::Whitespace, TrimIntoNone};
+    ///
+    /// let s = "\u{FEFF}a\u{200B}";
+    /// assert_eq!(Some(s), s.trim_into_none());
+    /// assert_eq!(Some("a"), s.trim_into_none_with(Whitespace::UnicodeAndZeroWidth));
+    /// ```
+    fn trim_into_none_with(self, whitespace: Whitespace) -> Option<Self::Trimmed>;
+}
+
+impl<'a> TrimIntoNone for &'a str {
+    type Trimmed = &'a str;
+
+    fn trim_into_none_with(self, whitespace: Whitespace) -> Option<&'a str> {
+        let trimmed = whitespace.trim(self);
+        (!trimmed.is_empty()).then_some(trimmed)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TrimIntoNone for String {
+    type Trimmed = String;
+
+    fn trim_into_none_with(mut self, whitespace: Whitespace) -> Option<String> {
+        let is_whitespace = |c| whitespace.is_whitespace(c);
+
+        let end = self.trim_end_matches(is_whitespace).len();
+        self.truncate(end);
+        let start = end - self.trim_start_matches(is_whitespace).len();
+        self.drain(..start);
+
+        (!self.is_empty()).then_some(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> TrimIntoNone for Cow<'a, str> {
+    type Trimmed = Cow<'a, str>;
+
+    fn trim_into_none_with(self, whitespace: Whitespace) -> Option<Cow<'a, str>> {
+        match self {
+            Cow::Borrowed(s) => s.trim_into_none_with(whitespace).map(Cow::Borrowed),
+            Cow::Owned(s) => s.trim_into_none_with(whitespace).map(Cow::Owned),
+        }
+    }
+}
+
+impl<T> TrimIntoNone for Option<T>
+where
+    T: TrimIntoNone,
+{
+    type Trimmed = T::Trimmed;
+
+    fn trim_into_none_with(self, whitespace: Whitespace) -> Option<T::Trimmed> {
+        self?.trim_into_none_with(whitespace)
+    }
+}
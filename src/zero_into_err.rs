@@ -0,0 +1,49 @@
+use super::is_zero::IsZero;
+
+/// Import this trait to add the `zero_into_err(op)` method to `Result<T, E>`,
+/// where `T` implements [`IsZero`].
+///
+/// It's the same as [`EmptyIntoErr`], for numbers where `0` means "unset".
+///
+/// [`IsZero`]: crate::is_zero::IsZero
+/// [`EmptyIntoErr`]: crate::empty_into_err::EmptyIntoErr
+pub trait ZeroIntoErr<T, E> {
+    /// If the value is `Result::Ok` with an inner value that
+    /// [`IsZero::is_zero`], calls `op` and returns its return value in
+    /// `Result::Err`. Otherwise the original value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use optempty::ZeroIntoErr;
+    /// #
+    /// let ok: Result<u16, &str> = Ok(0);
+    /// assert_eq!(Err("port not set"), ok.zero_into_err(|| "port not set"));
+    ///
+    /// let ok: Result<u16, &str> = Ok(8080);
+    /// assert_eq!(Ok(8080), ok.zero_into_err(|| "port not set"));
+    ///
+    /// let err: Result<u16, &str> = Err("failed");
+    /// assert_eq!(Err("failed"), err.zero_into_err(|| "port not set"));
+    /// ```
+    ///
+    /// [`IsZero::is_zero`]: crate::is_zero::IsZero::is_zero
+    fn zero_into_err<O>(self, op: O) -> Result<T, E>
+    where
+        O: FnOnce() -> E;
+}
+
+impl<T, E> ZeroIntoErr<T, E> for Result<T, E>
+where
+    T: IsZero,
+{
+    fn zero_into_err<O>(self, op: O) -> Result<T, E>
+    where
+        O: FnOnce() -> E,
+    {
+        match self {
+            Ok(value) if value.is_zero() => Err(op()),
+            other => other,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+/// Import this trait to add the `zero_into_none()` method to the primitive
+/// integers, and `Option`s of them.
+///
+/// Zero becomes `None`, and anything else becomes the matching `NonZero*`
+/// type, so the knowledge that it isn't zero is kept in the type. It's the
+/// same idea as [`EmptyIntoNone`], for numbers where `0` means "unset".
+///
+/// [`EmptyIntoNone`]: crate::empty_into_none::EmptyIntoNone
+pub trait ZeroIntoNone {
+    /// The non-zero counterpart of `Self`, e.g., `NonZeroU32` for `u32`.
+    type NonZero;
+
+    /// Returns `None` if the value is zero (or `None`), otherwise the value as
+    /// [`Self::NonZero`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::{NonZeroI8, NonZeroU32, NonZeroU64};
+    ///
+    /// use optempty::ZeroIntoNone;
+    ///
+    /// assert_eq!(None, 0_u32.zero_into_none());
+    /// assert_eq!(NonZeroU32::new(7), 7_u32.zero_into_none());
+    /// assert_eq!(NonZeroI8::new(-1), (-1_i8).zero_into_none());
+    ///
+    /// assert_eq!(None, Some(0_u64).zero_into_none());
+    /// assert_eq!(None, None::<u64>.zero_into_none());
+    /// assert_eq!(NonZeroU64::new(30), Some(30_u64).zero_into_none());
+    /// ```
+    fn zero_into_none(self) -> Option<Self::NonZero>;
+}
+
+macro_rules! impl_zero_into_none {
+    ($($ty:ty => $non_zero:ty),* $(,)?) => {
+        $(
+            impl ZeroIntoNone for $ty {
+                type NonZero = $non_zero;
+
+                fn zero_into_none(self) -> Option<$non_zero> {
+                    <$non_zero>::new(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_zero_into_none! {
+    u8 => NonZeroU8,
+    u16 => NonZeroU16,
+    u32 => NonZeroU32,
+    u64 => NonZeroU64,
+    u128 => NonZeroU128,
+    usize => NonZeroUsize,
+    i8 => NonZeroI8,
+    i16 => NonZeroI16,
+    i32 => NonZeroI32,
+    i64 => NonZeroI64,
+    i128 => NonZeroI128,
+    isize => NonZeroIsize,
+}
+
+impl<T> ZeroIntoNone for Option<T>
+where
+    T: ZeroIntoNone,
+{
+    type NonZero = T::NonZero;
+
+    fn zero_into_none(self) -> Option<T::NonZero> {
+        self?.zero_into_none()
+    }
+}
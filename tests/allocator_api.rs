@@ -0,0 +1,84 @@
+#![cfg(feature = "allocator_api")]
+#![feature(allocator_api, btreemap_alloc)]
+
+extern crate alloc;
+
+use alloc::{
+    alloc::{AllocError, Allocator, Global, Layout},
+    collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+};
+use core::ptr::NonNull;
+
+use optempty::{EmptyIntoNone, IsEmpty};
+
+/// Just forwards to `Global`, so it's a different type.
+#[derive(Debug, Clone, Copy, Default)]
+struct Forward;
+
+unsafe impl Allocator for Forward {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { Global.deallocate(ptr, layout) }
+    }
+}
+
+#[test]
+fn binary_heap() {
+    let mut bh = BinaryHeap::new_in(Forward);
+    assert!(IsEmpty::is_empty(&bh));
+
+    bh.push("a");
+    assert!(!IsEmpty::is_empty(&bh));
+}
+
+#[test]
+fn btree_map() {
+    let mut btm = BTreeMap::new_in(Forward);
+    assert!(IsEmpty::is_empty(&btm));
+
+    btm.insert("a", "b");
+    assert!(!IsEmpty::is_empty(&btm));
+}
+
+#[test]
+fn btree_set() {
+    let mut bts = BTreeSet::new_in(Forward);
+    assert!(IsEmpty::is_empty(&bts));
+
+    bts.insert("a");
+    assert!(!IsEmpty::is_empty(&bts));
+}
+
+#[test]
+fn linked_list() {
+    let mut ll = LinkedList::new_in(Forward);
+    assert!(IsEmpty::is_empty(&ll));
+
+    ll.push_back("a");
+    assert!(!IsEmpty::is_empty(&ll));
+}
+
+#[test]
+fn vec() {
+    let mut v = Vec::new_in(Forward);
+    assert!(IsEmpty::is_empty(&v));
+
+    v.push("a");
+    assert!(!IsEmpty::is_empty(&v));
+
+    assert!(Some(Vec::<&str, _>::new_in(Forward))
+        .empty_into_none()
+        .is_none());
+}
+
+#[test]
+fn vec_deque() {
+    let mut vd = VecDeque::new_in(Forward);
+    assert!(IsEmpty::is_empty(&vd));
+
+    vd.push_back("a");
+    assert!(!IsEmpty::is_empty(&vd));
+}
@@ -0,0 +1,171 @@
+#![cfg(feature = "axum")]
+
+use std::collections::HashMap;
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::Query,
+    http::{Request, StatusCode},
+    routing::{get, post},
+    Form, Json, Router,
+};
+use optempty::{
+    axum::{EmptyAsNone, NonEmpty},
+    IsEmpty,
+};
+use serde::Deserialize;
+use tower::ServiceExt;
+
+#[derive(Deserialize)]
+struct Filter {
+    name: Option<String>,
+}
+
+impl IsEmpty for Filter {
+    fn is_empty(&self) -> bool {
+        self.name.is_empty()
+    }
+}
+
+async fn form(NonEmpty(Form(fields)): NonEmpty<Form<HashMap<String, String>>>) -> String {
+    format!("{}", fields.len())
+}
+
+fn app() -> Router {
+    Router::new()
+        .route(
+            "/json",
+            post(|NonEmpty(Json(ids)): NonEmpty<Json<Vec<u32>>>| async move { format!("{ids:?}") }),
+        )
+        .route(
+            "/query",
+            get(
+                |NonEmpty(Query(params)): NonEmpty<Query<HashMap<String, String>>>| async move {
+                    format!("{}", params.len())
+                },
+            ),
+        )
+        .route("/form", get(form).post(form))
+        .route(
+            "/search",
+            get(
+                |EmptyAsNone(filter): EmptyAsNone<Query<Filter>>| async move {
+                    match filter {
+                        Some(Query(filter)) => format!("{:?}", filter.name),
+                        None => "everything".to_owned(),
+                    }
+                },
+            ),
+        )
+}
+
+async fn send(req: Request<Body>) -> (StatusCode, String) {
+    let res = app().oneshot(req).await.unwrap();
+    let status = res.status();
+    let body = to_bytes(res.into_body(), usize::MAX).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+async fn get_uri(uri: &str) -> (StatusCode, String) {
+    send(Request::get(uri).body(Body::empty()).unwrap()).await
+}
+
+async fn post_json(body: &'static str) -> (StatusCode, String) {
+    let req = Request::post("/json")
+        .header("content-type", "application/json")
+        .body(Body::from(body))
+        .unwrap();
+    send(req).await
+}
+
+#[tokio::test]
+async fn non_empty_json() {
+    assert_eq!(
+        (StatusCode::OK, "[1, 2]".to_owned()),
+        post_json("[1, 2]").await
+    );
+    assert_eq!(
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Request body must not be empty".to_owned()
+        ),
+        post_json("[]").await
+    );
+}
+
+#[tokio::test]
+async fn non_empty_json_keeps_inner_rejection() {
+    // No `content-type`, so `Json` rejects it before it's checked.
+    let req = Request::post("/json").body(Body::from("[]")).unwrap();
+    let (status, _) = send(req).await;
+    assert_eq!(StatusCode::UNSUPPORTED_MEDIA_TYPE, status);
+}
+
+#[tokio::test]
+async fn non_empty_query() {
+    assert_eq!(
+        (StatusCode::OK, "1".to_owned()),
+        get_uri("/query?a=1").await
+    );
+
+    for uri in ["/query", "/query?"] {
+        assert_eq!(
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "Request parameters must not be empty".to_owned()
+            ),
+            get_uri(uri).await
+        );
+    }
+}
+
+#[tokio::test]
+async fn non_empty_form() {
+    let form = |body: &'static str| {
+        Request::post("/form")
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from(body))
+            .unwrap()
+    };
+
+    assert_eq!((StatusCode::OK, "2".to_owned()), send(form("a=1&b=")).await);
+
+    assert_eq!(
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Request body must not be empty".to_owned()
+        ),
+        send(form("")).await
+    );
+}
+
+#[tokio::test]
+async fn non_empty_get_form() {
+    // `Form` reads the query string of `GET` requests.
+    assert_eq!((StatusCode::OK, "1".to_owned()), get_uri("/form?a=1").await);
+
+    for uri in ["/form", "/form?"] {
+        assert_eq!(
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "Request parameters must not be empty".to_owned()
+            ),
+            get_uri(uri).await
+        );
+    }
+}
+
+#[tokio::test]
+async fn empty_as_none_query() {
+    assert_eq!(
+        (StatusCode::OK, r#"Some("zoe")"#.to_owned()),
+        get_uri("/search?name=zoe").await
+    );
+
+    for uri in ["/search", "/search?", "/search?name="] {
+        assert_eq!(
+            (StatusCode::OK, "everything".to_owned()),
+            get_uri(uri).await
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use optempty::{is_blank::Whitespace, BlankIntoErr, BlankIntoNone, TrimIntoNone};
+
+#[test]
+fn blank_into_none() {
+    assert_eq!(None, Some("").blank_into_none());
+    assert_eq!(None, Some(" \n").blank_into_none());
+    assert_eq!(None, None::<&str>.blank_into_none());
+    assert_eq!(Some(" a "), Some(" a ").blank_into_none());
+
+    assert_eq!(
+        Some("\u{A0}"),
+        Some("\u{A0}").blank_into_none_with(Whitespace::Ascii)
+    );
+    assert_eq!(
+        None,
+        Some("\u{200B}").blank_into_none_with(Whitespace::UnicodeAndZeroWidth)
+    );
+}
+
+#[test]
+fn blank_into_err() {
+    let ok: Result<&str, &str> = Ok(" ");
+    assert_eq!(Err("blank"), ok.blank_into_err(|| "blank"));
+
+    let ok: Result<&str, &str> = Ok("a");
+    assert_eq!(Ok("a"), ok.blank_into_err(|| "blank"));
+
+    let err: Result<&str, &str> = Err("failed");
+    assert_eq!(Err("failed"), err.blank_into_err(|| "blank"));
+
+    let ok: Result<&str, &str> = Ok("\u{FEFF}");
+    assert_eq!(Ok("\u{FEFF}"), ok.blank_into_err(|| "blank"));
+    assert_eq!(
+        Err("blank"),
+        ok.blank_into_err_with(Whitespace::UnicodeAndZeroWidth, || "blank")
+    );
+}
+
+#[test]
+fn trim_str() {
+    assert_eq!(None, "".trim_into_none());
+    assert_eq!(None, " \t\n".trim_into_none());
+    assert_eq!(Some("a  b"), "  a  b\t".trim_into_none());
+    assert_eq!(Some("a"), Some(" a ").trim_into_none());
+    assert_eq!(None, None::<&str>.trim_into_none());
+
+    let s = "\u{A0}a\u{A0}";
+    assert_eq!(Some(s), s.trim_into_none_with(Whitespace::Ascii));
+    assert_eq!(Some("a"), s.trim_into_none_with(Whitespace::Unicode));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn trim_string() {
+    extern crate alloc;
+    use alloc::{borrow::Cow, string::String};
+
+    let s = String::with_capacity(64) + "  Zoe \n";
+    let ptr = s.as_ptr();
+    let trimmed = s.trim_into_none().unwrap();
+    assert_eq!("Zoe", trimmed);
+    // Trimmed in place.
+    assert_eq!(ptr, trimmed.as_ptr());
+    assert_eq!(64, trimmed.capacity());
+
+    assert_eq!(None, String::from(" \u{3000} ").trim_into_none());
+    assert_eq!(
+        Some(String::from("\u{3000}a\u{3000}")),
+        String::from(" \u{3000}a\u{3000} ").trim_into_none_with(Whitespace::Ascii)
+    );
+    assert_eq!(
+        Some(String::from("a")),
+        Some(String::from(" a ")).trim_into_none()
+    );
+
+    let borrowed = Cow::Borrowed(" a ").trim_into_none().unwrap();
+    assert!(matches!(borrowed, Cow::Borrowed("a")));
+    let owned = Cow::<str>::Owned(String::from(" a "))
+        .trim_into_none()
+        .unwrap();
+    assert!(matches!(owned, Cow::Owned(ref s) if s == "a"));
+    assert_eq!(None, Cow::Borrowed(" ").trim_into_none());
+}
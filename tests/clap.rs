@@ -0,0 +1,96 @@
+#![cfg(feature = "clap")]
+
+use std::{ffi::OsString, path::PathBuf};
+
+use clap::{
+    builder::{OsStringValueParser, PathBufValueParser, StringValueParser, TypedValueParser},
+    error::ErrorKind,
+    value_parser, Arg, ArgMatches, Command,
+};
+use optempty::clap::{EmptyAsNone, NonBlank, NonEmpty};
+
+fn cmd<P>(parser: P) -> Command
+where
+    P: TypedValueParser,
+{
+    Command::new("app").arg(Arg::new("value").long("value").value_parser(parser))
+}
+
+fn parse<P>(parser: P, value: &str) -> Result<ArgMatches, clap::Error>
+where
+    P: TypedValueParser,
+{
+    cmd(parser).try_get_matches_from(["app", "--value", value])
+}
+
+#[test]
+fn non_empty() {
+    let matches = parse(NonEmpty::new(StringValueParser::new()), "a").unwrap();
+    assert_eq!(Some(&"a".to_owned()), matches.get_one::<String>("value"));
+
+    let matches = parse(NonEmpty::new(PathBufValueParser::new()), "a/b").unwrap();
+    assert_eq!(Some(&PathBuf::from("a/b")), matches.get_one("value"));
+
+    let matches = parse(NonEmpty::new(OsStringValueParser::new()), " ").unwrap();
+    assert_eq!(Some(&OsString::from(" ")), matches.get_one("value"));
+
+    let err = parse(NonEmpty::new(StringValueParser::new()), "").unwrap_err();
+    assert_eq!(ErrorKind::InvalidValue, err.kind());
+    let err = parse(NonEmpty::new(PathBufValueParser::new()), "").unwrap_err();
+    assert_eq!(ErrorKind::InvalidValue, err.kind());
+    let err = parse(NonEmpty::new(OsStringValueParser::new()), "").unwrap_err();
+    assert_eq!(ErrorKind::InvalidValue, err.kind());
+    assert!(err
+        .to_string()
+        .contains("a value is required for '--value <value>' but none was supplied"));
+}
+
+#[test]
+fn non_empty_keeps_inner_errors() {
+    let parser = || NonEmpty::new(value_parser!(u16).range(1..));
+
+    let matches = parse(parser(), "8080").unwrap();
+    assert_eq!(Some(&8080), matches.get_one::<u16>("value"));
+
+    assert_eq!(
+        ErrorKind::ValueValidation,
+        parse(parser(), "0").unwrap_err().kind()
+    );
+}
+
+#[test]
+fn non_blank() {
+    let matches = parse(NonBlank::new(StringValueParser::new()), " a ").unwrap();
+    assert_eq!(Some(&" a ".to_owned()), matches.get_one::<String>("value"));
+
+    for value in ["", " ", "\t\n"] {
+        let err = parse(NonBlank::new(PathBufValueParser::new()), value).unwrap_err();
+        assert_eq!(ErrorKind::InvalidValue, err.kind());
+    }
+
+    let err = parse(NonBlank::new(StringValueParser::new()), "  ").unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("invalid value '  ' for '--value <value>'"));
+    assert!(message.contains("tip: the value must not be blank"));
+}
+
+#[test]
+fn empty_as_none() {
+    let get = |value| {
+        parse(EmptyAsNone::new(PathBufValueParser::new()), value)
+            .unwrap()
+            .get_one::<Option<PathBuf>>("value")
+            .cloned()
+    };
+
+    assert_eq!(Some(None), get(""));
+    assert_eq!(Some(Some(PathBuf::from("a"))), get("a"));
+
+    let matches = cmd(EmptyAsNone::new(StringValueParser::new()))
+        .try_get_matches_from(["app"])
+        .unwrap();
+    assert_eq!(None, matches.get_one::<Option<String>>("value"));
+
+    let err = parse(EmptyAsNone::new(value_parser!(u16)), "x").unwrap_err();
+    assert_eq!(ErrorKind::ValueValidation, err.kind());
+}
@@ -0,0 +1,182 @@
+#![cfg(all(feature = "derive", feature = "alloc"))]
+
+use std::collections::BTreeMap;
+
+use optempty::{EmptyIntoErr, EmptyIntoNone, IsEmpty};
+
+#[derive(Debug, Clone, PartialEq, IsEmpty)]
+struct Named {
+    names: Vec<String>,
+    nickname: Option<String>,
+    tags: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, IsEmpty)]
+struct Tuple(String, Vec<u8>);
+
+#[derive(Debug, Clone, PartialEq, IsEmpty)]
+struct Unit;
+
+#[derive(Debug, Clone, PartialEq, IsEmpty)]
+struct Skipped {
+    name: String,
+    #[is_empty(skip)]
+    version: u32,
+}
+
+fn is_zero(n: &u32) -> bool {
+    *n == 0
+}
+
+#[derive(Debug, Clone, PartialEq, IsEmpty)]
+struct With {
+    name: String,
+    #[is_empty(with = is_zero)]
+    count: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, IsEmpty)]
+struct Nested {
+    inner: Named,
+    tuple: Option<Tuple>,
+}
+
+#[derive(Debug, Clone, PartialEq, IsEmpty)]
+struct Generic<T> {
+    items: Vec<T>,
+    extra: Option<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, IsEmpty)]
+enum Filter {
+    #[is_empty]
+    All,
+    #[is_empty]
+    Unset(()),
+    Name(String),
+    Tags {
+        tags: Vec<String>,
+    },
+}
+
+fn named(names: &[&str]) -> Named {
+    Named {
+        names: names.iter().map(ToString::to_string).collect(),
+        nickname: None,
+        tags: BTreeMap::default(),
+    }
+}
+
+#[test]
+fn named_struct() {
+    assert!(named(&[]).is_empty());
+    assert!(!named(&["a"]).is_empty());
+
+    let mut n = named(&[]);
+    n.nickname = Some(String::new());
+    assert!(n.is_empty());
+
+    n.nickname = Some("a".into());
+    assert!(!n.is_empty());
+
+    let mut n = named(&[]);
+    n.tags.insert("a".into(), "b".into());
+    assert!(!n.is_empty());
+}
+
+#[test]
+fn tuple_struct() {
+    assert!(Tuple(String::new(), vec![]).is_empty());
+    assert!(!Tuple("a".into(), vec![]).is_empty());
+    assert!(!Tuple(String::new(), vec![1]).is_empty());
+}
+
+#[test]
+fn unit_struct() {
+    assert!(Unit.is_empty());
+}
+
+#[test]
+fn skip() {
+    assert!(Skipped {
+        name: String::new(),
+        version: 1,
+    }
+    .is_empty());
+    assert!(!Skipped {
+        name: "a".into(),
+        version: 0,
+    }
+    .is_empty());
+}
+
+#[test]
+fn with() {
+    assert!(With {
+        name: String::new(),
+        count: 0,
+    }
+    .is_empty());
+    assert!(!With {
+        name: String::new(),
+        count: 1,
+    }
+    .is_empty());
+}
+
+#[test]
+fn nested() {
+    let mut n = Nested {
+        inner: named(&[]),
+        tuple: Some(Tuple(String::new(), vec![])),
+    };
+    assert!(n.is_empty());
+
+    n.tuple = Some(Tuple("a".into(), vec![]));
+    assert!(!n.is_empty());
+
+    n.tuple = None;
+    n.inner = named(&["a"]);
+    assert!(!n.is_empty());
+}
+
+#[test]
+fn generic() {
+    let g: Generic<String> = Generic {
+        items: vec![],
+        extra: None,
+    };
+    assert!(g.is_empty());
+
+    let g = Generic {
+        items: vec![],
+        extra: Some(String::new()),
+    };
+    assert!(g.is_empty());
+
+    let g = Generic {
+        items: vec![String::new()],
+        extra: None,
+    };
+    assert!(!g.is_empty());
+}
+
+#[test]
+fn enum_variants() {
+    assert!(Filter::All.is_empty());
+    assert!(Filter::Unset(()).is_empty());
+    assert!(!Filter::Name(String::new()).is_empty());
+    assert!(!Filter::Tags { tags: vec![] }.is_empty());
+}
+
+#[test]
+fn empty_into() {
+    assert_eq!(None, Some(named(&[])).empty_into_none());
+    assert_eq!(Some(named(&["a"])), Some(named(&["a"])).empty_into_none());
+
+    assert_eq!(Err("was empty"), Ok(Unit).empty_into_err(|| "was empty"));
+    assert_eq!(
+        Ok(Filter::Name("a".into())),
+        Ok(Filter::Name("a".into())).empty_into_err(|| "was empty"),
+    );
+}
@@ -0,0 +1,246 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "alloc")]
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+
+use optempty::{Empty, IsEmpty, NoneIntoEmpty};
+
+fn check<T>()
+where
+    T: Empty + IsEmpty + std::fmt::Debug,
+{
+    let empty = T::empty();
+    assert!(IsEmpty::is_empty(&empty), "Should be empty: {empty:?}",);
+
+    let none: Option<T> = None;
+    assert!(IsEmpty::is_empty(&none.none_into_empty()));
+
+    let ok_none: Result<Option<T>, &str> = Ok(None);
+    assert!(IsEmpty::is_empty(&ok_none.none_into_empty().unwrap()));
+
+    let err: Result<Option<T>, &str> = Err("failed");
+    assert_eq!("failed", err.none_into_empty().unwrap_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn binary_heap() {
+    check::<BinaryHeap<&str>>();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn btree_map() {
+    check::<BTreeMap<&str, u32>>();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn btree_set() {
+    check::<BTreeSet<&str>>();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_map() {
+    check::<std::collections::HashMap<&str, u32>>();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_set() {
+    check::<std::collections::HashSet<&str>>();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_map_custom_hasher() {
+    use std::hash::{BuildHasherDefault, DefaultHasher};
+
+    check::<std::collections::HashMap<&str, u32, BuildHasherDefault<DefaultHasher>>>();
+    check::<std::collections::HashSet<&str, BuildHasherDefault<DefaultHasher>>>();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn linked_list() {
+    check::<LinkedList<&str>>();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec() {
+    check::<Vec<&str>>();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_deque() {
+    check::<VecDeque<&str>>();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn string() {
+    check::<String>();
+}
+
+#[test]
+fn str() {
+    check::<&str>();
+}
+
+#[test]
+fn slice() {
+    check::<&[u8]>();
+}
+
+#[test]
+fn c_str() {
+    check::<&core::ffi::CStr>();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn c_string() {
+    check::<alloc::ffi::CString>();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn os_str() {
+    check::<&std::ffi::OsStr>();
+    check::<std::ffi::OsString>();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn path() {
+    check::<&std::path::Path>();
+    check::<std::path::PathBuf>();
+}
+
+#[test]
+fn option() {
+    check::<Option<&str>>();
+    assert_eq!(None, Option::<&str>::empty());
+}
+
+#[test]
+fn result() {
+    assert_eq!(Ok(""), Result::<&str, &str>::empty());
+}
+
+mod impl_empty {
+    use optempty::Empty;
+
+    #[derive(Debug)]
+    struct Simple(Vec<u8>);
+
+    impl Simple {
+        fn new() -> Self {
+            Simple(Vec::new())
+        }
+    }
+
+    optempty::impl_empty!(Simple);
+
+    #[derive(Debug)]
+    struct Bounded<T>(Vec<T>);
+
+    impl<T> Bounded<T>
+    where
+        T: PartialEq,
+    {
+        fn new() -> Self {
+            Bounded(Vec::new())
+        }
+    }
+
+    optempty::impl_empty!(Bounded; T where T: PartialEq);
+
+    #[derive(Debug)]
+    struct Borrowed<'a, T>(&'a [T]);
+
+    impl<T> Borrowed<'_, T> {
+        fn new() -> Self {
+            Borrowed(&[])
+        }
+    }
+
+    optempty::impl_empty!(['a, T] Borrowed<'a, T>);
+
+    #[test]
+    fn forms() {
+        assert!(Simple::empty().0.is_empty());
+        assert!(Bounded::<u8>::empty().0.is_empty());
+        assert!(Borrowed::<u8>::empty().0.is_empty());
+        assert!(Option::<Simple>::empty().is_none());
+    }
+}
+
+#[test]
+fn none_into_empty_keeps_values() {
+    assert_eq!("a", Some("a").none_into_empty());
+    assert_eq!(Ok("a"), Result::<_, &str>::Ok(Some("a")).none_into_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn none_into_empty_keeps_owned_values() {
+    assert_eq!(vec!["a"], Some(vec!["a"]).none_into_empty());
+    assert_eq!(
+        Ok(String::from("a")),
+        Result::<_, &str>::Ok(Some(String::from("a"))).none_into_empty(),
+    );
+}
+
+#[cfg(all(feature = "querymap", feature = "std"))]
+#[test]
+fn query_map_map() {
+    check::<query_map::QueryMap>();
+}
+
+#[cfg(feature = "serdejson")]
+#[test]
+fn serde_json_map() {
+    check::<serde_json::Map<String, serde_json::Value>>();
+}
+
+#[cfg(feature = "serdejson")]
+#[test]
+fn serde_json_value() {
+    check::<serde_json::Value>();
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_table() {
+    check::<toml::Table>();
+}
+
+#[cfg(feature = "serde_yaml")]
+#[test]
+fn serde_yaml_mapping() {
+    check::<serde_yaml::Mapping>();
+}
+
+#[cfg(feature = "serde_yaml")]
+#[test]
+fn serde_yaml_value() {
+    check::<serde_yaml::Value>();
+}
+
+#[cfg(feature = "ciborium")]
+#[test]
+fn ciborium_value() {
+    check::<ciborium::Value>();
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn http_types() {
+    check::<http::HeaderMap>();
+    check::<http::HeaderValue>();
+    check::<http::Extensions>();
+}
@@ -1,4 +1,6 @@
+#[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "alloc")]
 use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
 
 use optempty::{EmptyIntoErr, EmptyIntoNone};
@@ -98,6 +100,7 @@ where
     check_result(col);
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn binary_heap() {
     let mut bh = BinaryHeap::default();
@@ -111,6 +114,7 @@ fn binary_heap() {
     check(bh);
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn btree_map() {
     let mut btm = BTreeMap::default();
@@ -124,6 +128,7 @@ fn btree_map() {
     check(btm.clone());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn btree_set() {
     let mut bts = BTreeSet::default();
@@ -140,7 +145,7 @@ fn btree_set() {
 #[cfg(feature = "std")]
 #[test]
 fn hash_map() {
-    let mut hm = std::collections::HashMap::default();
+    let mut hm: std::collections::HashMap<_, _> = std::collections::HashMap::default();
     check(hm.clone());
 
     hm.insert("a", 1);
@@ -154,7 +159,7 @@ fn hash_map() {
 #[cfg(feature = "std")]
 #[test]
 fn hash_set() {
-    let mut hs = std::collections::HashSet::default();
+    let mut hs: std::collections::HashSet<_> = std::collections::HashSet::default();
     check(hs.clone());
 
     hs.insert("a");
@@ -165,6 +170,7 @@ fn hash_set() {
     check(hs);
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn linked_list() {
     let mut ll = LinkedList::default();
@@ -178,6 +184,7 @@ fn linked_list() {
     check(ll);
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn vec() {
     let mut v = vec![];
@@ -191,6 +198,7 @@ fn vec() {
     check(v);
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn vec_deque() {
     let mut vd = VecDeque::default();
@@ -204,6 +212,7 @@ fn vec_deque() {
     check(vd);
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn string() {
     check(String::default());
@@ -225,6 +234,26 @@ fn slice() {
     check("abc".as_bytes());
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn smart_pointers() {
+    use alloc::{borrow::Cow, boxed::Box, rc::Rc, sync::Arc};
+
+    check(Box::<str>::from(""));
+    check(Box::<str>::from("abc"));
+    check(Rc::<[u8]>::from([]));
+    check(Rc::<[u8]>::from([1, 2, 3]));
+    check(Arc::<str>::from(""));
+    check(Arc::<str>::from("abc"));
+    check(Arc::new(String::new()));
+    check(Arc::new(String::from("abc")));
+    check(Cow::Borrowed(""));
+    check(Cow::<str>::Owned("abc".into()));
+
+    let some: Option<Arc<str>> = Some(Arc::from(""));
+    assert_eq!(None, some.empty_into_none());
+}
+
 #[cfg(all(feature = "querymap", feature = "std"))]
 #[test]
 fn query_map_map() {
@@ -261,3 +290,24 @@ fn serde_json_map() {
     map.insert("c".into(), 3.into());
     check(map);
 }
+
+#[cfg(feature = "serdejson")]
+#[test]
+fn serde_json_value() {
+    use serde_json::{json, Value};
+
+    check(json!(null));
+    check(json!(""));
+    check(json!("abc"));
+    check(json!([]));
+    check(json!([1, 2, 3]));
+    check(json!({}));
+    check(json!({"a": 1}));
+    check(json!(0));
+
+    let parsed: Option<Value> = serde_json::from_str("[]").ok();
+    assert_eq!(None, parsed.empty_into_none());
+
+    let parsed: Result<Value, &str> = serde_json::from_str("{}").map_err(|_| "invalid");
+    assert_eq!(Err("was empty"), parsed.empty_into_err(|| "was empty"));
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "std")]
+
+use std::{env, ffi::OsString, num::ParseIntError};
+
+use optempty::env::{
+    parse_non_empty, var_non_empty, var_os_non_empty, vars_non_empty, ParseVarError,
+};
+
+// Tests run in parallel, so each one uses its own variables.
+
+#[test]
+fn var() {
+    env::remove_var("OPTEMPTY_TEST_VAR");
+    assert_eq!(None, var_non_empty("OPTEMPTY_TEST_VAR"));
+    assert_eq!(None, var_os_non_empty("OPTEMPTY_TEST_VAR"));
+
+    env::set_var("OPTEMPTY_TEST_VAR", "");
+    assert_eq!(None, var_non_empty("OPTEMPTY_TEST_VAR"));
+    assert_eq!(None, var_os_non_empty("OPTEMPTY_TEST_VAR"));
+
+    env::set_var("OPTEMPTY_TEST_VAR", " ");
+    assert_eq!(Some(" ".to_owned()), var_non_empty("OPTEMPTY_TEST_VAR"));
+    assert_eq!(
+        Some(OsString::from(" ")),
+        var_os_non_empty("OPTEMPTY_TEST_VAR")
+    );
+}
+
+#[test]
+fn parse() {
+    env::remove_var("OPTEMPTY_TEST_PARSE");
+    assert_eq!(Ok(None), parse_non_empty::<u8>("OPTEMPTY_TEST_PARSE"));
+
+    env::set_var("OPTEMPTY_TEST_PARSE", "");
+    assert_eq!(Ok(None), parse_non_empty::<u8>("OPTEMPTY_TEST_PARSE"));
+
+    env::set_var("OPTEMPTY_TEST_PARSE", "42");
+    assert_eq!(Ok(Some(42)), parse_non_empty::<u8>("OPTEMPTY_TEST_PARSE"));
+
+    env::set_var("OPTEMPTY_TEST_PARSE", "256");
+    let err: ParseVarError<ParseIntError> =
+        parse_non_empty::<u8>("OPTEMPTY_TEST_PARSE").unwrap_err();
+    assert!(matches!(err, ParseVarError::Parse(_)));
+    assert_eq!(
+        "environment variable could not be parsed: number too large to fit in target type",
+        err.to_string()
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn parse_not_unicode() {
+    use std::os::unix::ffi::OsStringExt;
+
+    let value = OsString::from_vec(vec![0xff]);
+    env::set_var("OPTEMPTY_TEST_NOT_UNICODE", &value);
+
+    assert_eq!(None, var_non_empty("OPTEMPTY_TEST_NOT_UNICODE"));
+    assert_eq!(
+        Some(value.clone()),
+        var_os_non_empty("OPTEMPTY_TEST_NOT_UNICODE")
+    );
+    assert_eq!(
+        Err(ParseVarError::NotUnicode(value)),
+        parse_non_empty::<String>("OPTEMPTY_TEST_NOT_UNICODE")
+    );
+}
+
+#[test]
+fn vars() {
+    env::set_var("OPTEMPTY_TEST_VARS_A", "a");
+    env::set_var("OPTEMPTY_TEST_VARS_B", "");
+    env::set_var("OPTEMPTY_TEST_VARS_C", "c");
+    env::set_var("OPTEMPTY_TEST_VARSX", "x");
+
+    let mut vars: Vec<_> = vars_non_empty("OPTEMPTY_TEST_VARS_").collect();
+    vars.sort();
+    assert_eq!(
+        vec![
+            ("OPTEMPTY_TEST_VARS_A".to_owned(), "a".to_owned()),
+            ("OPTEMPTY_TEST_VARS_C".to_owned(), "c".to_owned()),
+        ],
+        vars
+    );
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "http")]
+
+use http::{
+    header::{ACCEPT, CONTENT_TYPE},
+    HeaderMap, HeaderValue,
+};
+use optempty::http::HeaderMapNonEmpty;
+
+#[test]
+fn get_non_empty() {
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static(""));
+    headers.append(ACCEPT, HeaderValue::from_static(""));
+    headers.append(ACCEPT, HeaderValue::from_static("text/html"));
+    headers.append(ACCEPT, HeaderValue::from_static("text/plain"));
+
+    assert_eq!(None, headers.get_non_empty(CONTENT_TYPE));
+    assert_eq!(None, headers.get_non_empty("x-missing"));
+    assert_eq!(
+        Some(&HeaderValue::from_static("text/html")),
+        headers.get_non_empty(ACCEPT)
+    );
+    assert_eq!(
+        Some(&HeaderValue::from_static("text/html")),
+        headers.get_non_empty("Accept")
+    );
+}
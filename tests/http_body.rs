@@ -0,0 +1,109 @@
+#![cfg(feature = "http-body")]
+
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use http::HeaderMap;
+use http_body::{Body, Frame};
+use http_body_util::{BodyExt, Empty, Full};
+use optempty::http_body::BodyEmptyIntoNone;
+
+/// A body that doesn't know its own size, so it has to be polled.
+struct Frames(VecDeque<Result<Frame<Bytes>, &'static str>>);
+
+impl Frames {
+    fn new(frames: impl IntoIterator<Item = Result<Frame<Bytes>, &'static str>>) -> Self {
+        Self(frames.into_iter().collect())
+    }
+}
+
+impl Body for Frames {
+    type Data = Bytes;
+    type Error = &'static str;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Bytes>, &'static str>>> {
+        Poll::Ready(self.get_mut().0.pop_front())
+    }
+}
+
+fn data(s: &'static str) -> Result<Frame<Bytes>, &'static str> {
+    Ok(Frame::data(Bytes::from(s)))
+}
+
+#[tokio::test]
+async fn known_empty() {
+    assert!(Empty::<Bytes>::new().empty_into_none().await.is_none());
+    assert!(Full::new(Bytes::new()).empty_into_none().await.is_none());
+}
+
+#[tokio::test]
+async fn known_non_empty() {
+    let body = Full::new(Bytes::from("hello"))
+        .empty_into_none()
+        .await
+        .unwrap();
+    assert_eq!(Some(5), body.size_hint().exact());
+    assert!(!body.is_end_stream());
+    assert_eq!("hello", body.collect().await.unwrap().to_bytes());
+}
+
+#[tokio::test]
+async fn polled_empty() {
+    assert!(Frames::new([]).empty_into_none().await.is_none());
+    assert!(Frames::new([data(""), data("")])
+        .empty_into_none()
+        .await
+        .is_none());
+}
+
+#[tokio::test]
+async fn polled_non_empty() {
+    let body = Frames::new([data(""), data("hello, "), data(""), data("world")])
+        .empty_into_none()
+        .await
+        .unwrap();
+    assert_eq!(7, body.size_hint().lower());
+    assert_eq!("hello, world", body.collect().await.unwrap().to_bytes());
+}
+
+#[tokio::test]
+async fn trailers_only() {
+    let mut trailers = HeaderMap::new();
+    trailers.insert("x-checksum", "abc".parse().unwrap());
+
+    assert!(Frames::new([Ok(Frame::trailers(trailers.clone()))])
+        .empty_into_none()
+        .await
+        .is_none());
+    assert!(
+        Frames::new([data(""), Ok(Frame::trailers(trailers.clone()))])
+            .empty_into_none()
+            .await
+            .is_none()
+    );
+
+    // Trailers after data are kept.
+    let body = Frames::new([data("hello"), Ok(Frame::trailers(trailers.clone()))])
+        .empty_into_none()
+        .await
+        .unwrap();
+    let collected = body.collect().await.unwrap();
+    assert_eq!(Some(&trailers), collected.trailers());
+    assert_eq!("hello", collected.to_bytes());
+}
+
+#[tokio::test]
+async fn first_error_is_kept() {
+    let body = Frames::new([Err("oops"), data("never read")])
+        .empty_into_none()
+        .await
+        .unwrap();
+    assert_eq!(Err("oops"), body.collect().await.map(|_| ()));
+}
@@ -0,0 +1,88 @@
+use optempty::{is_blank::Whitespace, IsBlank};
+
+const ALL: [Whitespace; 3] = [
+    Whitespace::Ascii,
+    Whitespace::Unicode,
+    Whitespace::UnicodeAndZeroWidth,
+];
+
+#[test]
+fn str() {
+    for whitespace in ALL {
+        assert!("".is_blank_with(whitespace));
+        assert!(" \t\r\n".is_blank_with(whitespace));
+        assert!(!" a ".is_blank_with(whitespace));
+    }
+
+    // No-break space and ideographic space.
+    for s in ["\u{A0}", "\u{3000}"] {
+        assert!(!s.is_blank_with(Whitespace::Ascii));
+        assert!(s.is_blank_with(Whitespace::Unicode));
+        assert!(s.is_blank_with(Whitespace::UnicodeAndZeroWidth));
+    }
+
+    for s in [
+        "\u{200B}",
+        "\u{200C}",
+        "\u{200D}",
+        "\u{2060}",
+        "\u{FEFF}",
+        " \u{200B} ",
+    ] {
+        assert!(!s.is_blank_with(Whitespace::Ascii));
+        assert!(!s.is_blank_with(Whitespace::Unicode));
+        assert!(s.is_blank_with(Whitespace::UnicodeAndZeroWidth));
+    }
+
+    assert_eq!(Whitespace::Unicode, Whitespace::default());
+    assert!("\u{A0}".is_blank());
+    assert!(!"\u{200B}".is_blank());
+}
+
+#[test]
+fn option_and_result() {
+    assert!(None::<&str>.is_blank());
+    assert!(Some("").is_blank());
+    assert!(Some(" ").is_blank());
+    assert!(Some(Some(" ")).is_blank());
+    assert!(!Some("a").is_blank());
+
+    assert!(Ok::<_, ()>(" ").is_blank());
+    assert!(Ok::<_, ()>(Some(" ")).is_blank());
+    assert!(!Ok::<_, ()>("a").is_blank());
+    assert!(!Err::<&str, _>(" ").is_blank());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn alloc() {
+    extern crate alloc;
+    use alloc::{borrow::Cow, boxed::Box, rc::Rc, string::String, sync::Arc};
+
+    assert!(String::from(" \n").is_blank());
+    assert!(!String::from(" a").is_blank());
+    assert!(Cow::Borrowed(" ").is_blank());
+    assert!(Cow::<str>::Owned(String::from(" ")).is_blank());
+    assert!(!Cow::Borrowed("a").is_blank());
+    assert!(Box::<str>::from(" ").is_blank());
+    assert!(Rc::<str>::from(" ").is_blank());
+    assert!(Arc::<str>::from(" ").is_blank());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn os_str() {
+    use std::ffi::{OsStr, OsString};
+
+    assert!(OsStr::new("").is_blank());
+    assert!(OsStr::new(" \t").is_blank());
+    assert!(OsString::from(" ").is_blank());
+    assert!(!OsStr::new(" a ").is_blank());
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+
+        assert!(!OsStr::from_bytes(b" \xff ").is_blank());
+    }
+}
@@ -1,7 +1,9 @@
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
 use optempty::IsEmpty;
 
+#[cfg(feature = "alloc")]
 #[test]
 fn binary_heap() {
     let mut bh = alloc::collections::BinaryHeap::default();
@@ -11,6 +13,7 @@ fn binary_heap() {
     assert!(!IsEmpty::is_empty(&bh));
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn btree_map() {
     let mut btm = alloc::collections::BTreeMap::default();
@@ -20,6 +23,7 @@ fn btree_map() {
     assert!(!IsEmpty::is_empty(&btm));
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn btree_set() {
     let mut bts = alloc::collections::BTreeSet::default();
@@ -32,7 +36,7 @@ fn btree_set() {
 #[cfg(feature = "std")]
 #[test]
 fn hash_map() {
-    let mut hm = std::collections::HashMap::default();
+    let mut hm: std::collections::HashMap<_, _> = std::collections::HashMap::default();
     assert!(IsEmpty::is_empty(&hm));
 
     hm.insert("a", "b");
@@ -42,13 +46,39 @@ fn hash_map() {
 #[cfg(feature = "std")]
 #[test]
 fn hash_set() {
-    let mut hs = std::collections::HashSet::default();
+    let mut hs: std::collections::HashSet<_> = std::collections::HashSet::default();
     assert!(IsEmpty::is_empty(&hs));
 
     hs.insert("a");
     assert!(!IsEmpty::is_empty(&hs));
 }
 
+#[cfg(feature = "std")]
+#[test]
+fn hash_map_custom_hasher() {
+    use std::{collections::HashMap, hash::BuildHasherDefault};
+
+    let mut hm: HashMap<&str, &str, BuildHasherDefault<std::hash::DefaultHasher>> =
+        HashMap::default();
+    assert!(IsEmpty::is_empty(&hm));
+
+    hm.insert("a", "b");
+    assert!(!IsEmpty::is_empty(&hm));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_set_custom_hasher() {
+    use std::{collections::HashSet, hash::BuildHasherDefault};
+
+    let mut hs: HashSet<&str, BuildHasherDefault<std::hash::DefaultHasher>> = HashSet::default();
+    assert!(IsEmpty::is_empty(&hs));
+
+    hs.insert("a");
+    assert!(!IsEmpty::is_empty(&hs));
+}
+
+#[cfg(feature = "alloc")]
 #[test]
 fn linked_list() {
     let mut ll = alloc::collections::LinkedList::default();
@@ -58,6 +88,7 @@ fn linked_list() {
     assert!(!IsEmpty::is_empty(&ll));
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn vec() {
     let mut v = vec![];
@@ -67,6 +98,7 @@ fn vec() {
     assert!(!IsEmpty::is_empty(&v));
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn vec_deque() {
     let mut vd = alloc::collections::VecDeque::default();
@@ -76,6 +108,7 @@ fn vec_deque() {
     assert!(!IsEmpty::is_empty(&vd));
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn string() {
     assert!(IsEmpty::is_empty(&String::default()));
@@ -94,6 +127,115 @@ fn slice() {
     assert!(!IsEmpty::is_empty(&"a".as_bytes()));
 }
 
+#[test]
+fn c_str() {
+    assert!(IsEmpty::is_empty(c""));
+    assert!(!IsEmpty::is_empty(c"a"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn c_string() {
+    use alloc::ffi::CString;
+
+    assert!(IsEmpty::is_empty(&CString::default()));
+    assert!(!IsEmpty::is_empty(&CString::new("a").unwrap()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn os_str() {
+    use std::ffi::OsStr;
+
+    assert!(IsEmpty::is_empty(OsStr::new("")));
+    assert!(!IsEmpty::is_empty(OsStr::new("a")));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn os_string() {
+    use std::ffi::OsString;
+
+    assert!(IsEmpty::is_empty(&OsString::new()));
+    assert!(!IsEmpty::is_empty(&OsString::from("a")));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn path() {
+    use std::path::Path;
+
+    assert!(IsEmpty::is_empty(Path::new("")));
+    assert!(!IsEmpty::is_empty(Path::new("a")));
+    assert!(!IsEmpty::is_empty(Path::new("/")));
+    assert!(!IsEmpty::is_empty(Path::new(".")));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn path_buf() {
+    use std::path::PathBuf;
+
+    assert!(IsEmpty::is_empty(&PathBuf::new()));
+    assert!(!IsEmpty::is_empty(&PathBuf::from("a")));
+}
+
+#[test]
+fn unsized_types() {
+    assert!(IsEmpty::is_empty(""));
+    assert!(!IsEmpty::is_empty("a"));
+
+    let empty: &[u8] = &[];
+    assert!(IsEmpty::is_empty(empty));
+    assert!(!IsEmpty::is_empty(&[1][..]));
+}
+
+#[test]
+fn mut_ref() {
+    let mut empty = "";
+    assert!(IsEmpty::is_empty(&&mut empty));
+
+    let mut a = "a";
+    assert!(!IsEmpty::is_empty(&&mut a));
+}
+
+#[test]
+fn pin() {
+    use core::pin::Pin;
+
+    assert!(IsEmpty::is_empty(&Pin::new("")));
+    assert!(!IsEmpty::is_empty(&Pin::new("a")));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn smart_pointers() {
+    use alloc::{borrow::Cow, boxed::Box, rc::Rc, sync::Arc};
+
+    assert!(IsEmpty::is_empty(&Box::<str>::from("")));
+    assert!(!IsEmpty::is_empty(&Box::<str>::from("a")));
+    assert!(IsEmpty::is_empty(&Box::<[u8]>::from([])));
+    assert!(!IsEmpty::is_empty(&Box::<[u8]>::from([1])));
+
+    assert!(IsEmpty::is_empty(&Rc::<str>::from("")));
+    assert!(!IsEmpty::is_empty(&Rc::<str>::from("a")));
+    assert!(IsEmpty::is_empty(&Rc::new(Vec::<u8>::new())));
+    assert!(!IsEmpty::is_empty(&Rc::new(vec![1])));
+
+    assert!(IsEmpty::is_empty(&Arc::<[u8]>::from([])));
+    assert!(!IsEmpty::is_empty(&Arc::<[u8]>::from([1])));
+    assert!(IsEmpty::is_empty(&Arc::new(String::new())));
+    assert!(!IsEmpty::is_empty(&Arc::new(String::from("a"))));
+
+    assert!(IsEmpty::is_empty(&Cow::Borrowed("")));
+    assert!(!IsEmpty::is_empty(&Cow::<str>::Owned("a".into())));
+    assert!(IsEmpty::is_empty(&Cow::<[u8]>::Borrowed(&[])));
+    assert!(!IsEmpty::is_empty(&Cow::<[u8]>::Owned(vec![1])));
+
+    assert!(IsEmpty::is_empty(&Box::pin(String::new())));
+    assert!(!IsEmpty::is_empty(&Box::pin(String::from("a"))));
+}
+
 #[cfg(all(feature = "querymap", feature = "std"))]
 #[test]
 fn query_map_map() {
@@ -119,3 +261,245 @@ fn serde_json_map() {
     map.insert("a".into(), 1.into());
     assert!(!IsEmpty::is_empty(&map));
 }
+
+#[cfg(feature = "serdejson")]
+#[test]
+fn serde_json_value() {
+    use serde_json::json;
+
+    assert!(IsEmpty::is_empty(&json!(null)));
+    assert!(IsEmpty::is_empty(&json!("")));
+    assert!(IsEmpty::is_empty(&json!([])));
+    assert!(IsEmpty::is_empty(&json!({})));
+
+    assert!(!IsEmpty::is_empty(&json!(0)));
+    assert!(!IsEmpty::is_empty(&json!(false)));
+    assert!(!IsEmpty::is_empty(&json!("a")));
+    assert!(!IsEmpty::is_empty(&json!([null])));
+    assert!(!IsEmpty::is_empty(&json!({"a": null})));
+}
+
+#[cfg(feature = "serdejson")]
+#[test]
+fn serde_json_raw_value() {
+    use serde_json::value::RawValue;
+
+    fn raw(json: &str) -> Box<RawValue> {
+        RawValue::from_string(json.into()).unwrap()
+    }
+
+    for json in ["null", r#""""#, "[]", "[ ]", "{}", "{\n\t}"] {
+        assert!(IsEmpty::is_empty(&raw(json)), "{json} should be empty");
+    }
+
+    for json in ["0", "false", r#""a""#, "[null]", r#"{"a":null}"#, r#"" ""#] {
+        assert!(!IsEmpty::is_empty(&raw(json)), "{json} should not be empty");
+    }
+
+    #[derive(serde::Deserialize)]
+    struct Payload<'a> {
+        #[serde(borrow)]
+        data: &'a RawValue,
+    }
+
+    let payload: Payload = serde_json::from_str(r#"{"data": [ ] }"#).unwrap();
+    assert!(IsEmpty::is_empty(payload.data));
+}
+
+mod impl_is_empty {
+    use optempty::IsEmpty;
+
+    struct Simple(Vec<u8>);
+
+    impl Simple {
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+    }
+
+    optempty::impl_is_empty!(Simple);
+
+    struct Generic<K, V>(Vec<(K, V)>);
+
+    impl<K, V> Generic<K, V> {
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+    }
+
+    optempty::impl_is_empty!(Generic; K, V);
+
+    struct Bounded<T>(Vec<T>);
+
+    impl<T> Bounded<T>
+    where
+        T: PartialEq,
+    {
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+    }
+
+    optempty::impl_is_empty!(Bounded; T where T: PartialEq);
+
+    struct Array<T, const N: usize> {
+        _items: [Option<T>; N],
+        len: usize,
+    }
+
+    impl<T, const N: usize> Array<T, N> {
+        fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+    }
+
+    optempty::impl_is_empty!([T, const N: usize] Array<T, N>);
+
+    struct Borrowed<'a, T>(&'a [T]);
+
+    impl<T> Borrowed<'_, T>
+    where
+        T: Clone,
+    {
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+    }
+
+    optempty::impl_is_empty!(['a, T] Borrowed<'a, T> where T: Clone);
+
+    #[test]
+    fn simple() {
+        assert!(IsEmpty::is_empty(&Simple(vec![])));
+        assert!(!IsEmpty::is_empty(&Simple(vec![1])));
+    }
+
+    #[test]
+    fn generic() {
+        assert!(IsEmpty::is_empty(&Generic::<u8, u8>(vec![])));
+        assert!(!IsEmpty::is_empty(&Generic(vec![(1, 2)])));
+    }
+
+    #[test]
+    fn where_clause() {
+        assert!(IsEmpty::is_empty(&Bounded::<u8>(vec![])));
+        assert!(!IsEmpty::is_empty(&Bounded(vec![1])));
+    }
+
+    #[test]
+    fn const_generic() {
+        let empty = Array::<u8, 2> {
+            _items: [None, None],
+            len: 0,
+        };
+        assert!(IsEmpty::is_empty(&empty));
+
+        let full = Array {
+            _items: [Some(1), Some(2)],
+            len: 2,
+        };
+        assert!(!IsEmpty::is_empty(&full));
+    }
+
+    #[test]
+    fn lifetime() {
+        assert!(IsEmpty::is_empty(&Borrowed::<u8>(&[])));
+        assert!(!IsEmpty::is_empty(&Borrowed(&[1])));
+    }
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_value() {
+    use toml::{Table, Value};
+
+    assert!(IsEmpty::is_empty(&Table::new()));
+    assert!(IsEmpty::is_empty(&Value::String(String::new())));
+    assert!(IsEmpty::is_empty(&Value::Array(vec![])));
+    assert!(IsEmpty::is_empty(&Value::Table(Table::new())));
+
+    let table: Table = toml::from_str("a = ''").unwrap();
+    assert!(!IsEmpty::is_empty(&table));
+    assert!(!IsEmpty::is_empty(&Value::Table(table)));
+    assert!(!IsEmpty::is_empty(&Value::Integer(0)));
+    assert!(!IsEmpty::is_empty(&Value::Boolean(false)));
+    assert!(!IsEmpty::is_empty(&Value::String("a".into())));
+}
+
+#[cfg(feature = "serde_yaml")]
+#[test]
+fn serde_yaml_value() {
+    use serde_yaml::{Mapping, Sequence, Value};
+
+    fn yaml(yaml: &str) -> Value {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    assert!(IsEmpty::is_empty(&Mapping::new()));
+    assert!(IsEmpty::is_empty(&Sequence::new()));
+    for empty in ["~", "null", "''", "[]", "{}"] {
+        assert!(IsEmpty::is_empty(&yaml(empty)), "{empty} should be empty");
+    }
+
+    for non_empty in ["0", "false", "a", "[~]", "{a: ~}", "!tag ''"] {
+        assert!(
+            !IsEmpty::is_empty(&yaml(non_empty)),
+            "{non_empty} should not be empty"
+        );
+    }
+}
+
+#[cfg(feature = "ciborium")]
+#[test]
+fn ciborium_value() {
+    use ciborium::Value;
+
+    assert!(IsEmpty::is_empty(&Value::Null));
+    assert!(IsEmpty::is_empty(&Value::Text(String::new())));
+    assert!(IsEmpty::is_empty(&Value::Bytes(vec![])));
+    assert!(IsEmpty::is_empty(&Value::Array(vec![])));
+    assert!(IsEmpty::is_empty(&Value::Map(vec![])));
+
+    assert!(!IsEmpty::is_empty(&Value::Integer(0.into())));
+    assert!(!IsEmpty::is_empty(&Value::Float(0.0)));
+    assert!(!IsEmpty::is_empty(&Value::Bool(false)));
+    assert!(!IsEmpty::is_empty(&Value::Text("a".into())));
+    assert!(!IsEmpty::is_empty(&Value::Array(vec![Value::Null])));
+    assert!(!IsEmpty::is_empty(&Value::Tag(0, Box::new(Value::Null))));
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn http_types() {
+    use http::{uri::PathAndQuery, Extensions, HeaderMap, HeaderValue};
+
+    assert!(IsEmpty::is_empty(&HeaderMap::<HeaderValue>::new()));
+    assert!(IsEmpty::is_empty(&HeaderValue::from_static("")));
+    assert!(IsEmpty::is_empty(&Extensions::new()));
+    assert!(IsEmpty::is_empty(&PathAndQuery::from_static("/")));
+    assert!(IsEmpty::is_empty(&PathAndQuery::from_static("/a/b?")));
+
+    let mut headers = HeaderMap::new();
+    headers.insert("x-empty", HeaderValue::from_static(""));
+    assert!(!IsEmpty::is_empty(&headers));
+    assert!(!IsEmpty::is_empty(&HeaderValue::from_static(" ")));
+
+    let mut extensions = Extensions::new();
+    extensions.insert(0_u8);
+    assert!(!IsEmpty::is_empty(&extensions));
+    assert!(!IsEmpty::is_empty(&PathAndQuery::from_static("/?a")));
+}
+
+#[cfg(feature = "axum")]
+#[test]
+fn axum_extractors() {
+    use axum::{extract::Query, Form, Json};
+
+    assert!(IsEmpty::is_empty(&Json(Vec::<u8>::new())));
+    assert!(IsEmpty::is_empty(&Query(String::new())));
+    assert!(IsEmpty::is_empty(&Form(Some(""))));
+
+    assert!(!IsEmpty::is_empty(&Json(vec![1])));
+    assert!(!IsEmpty::is_empty(&Query("a")));
+    assert!(!IsEmpty::is_empty(&Form(Some("a"))));
+}
@@ -0,0 +1,44 @@
+use std::{num::Wrapping, time::Duration};
+
+use optempty::IsZero;
+
+macro_rules! check_ints {
+    ($($ty:ty),*) => {
+        $(
+            assert!((0 as $ty).is_zero(), stringify!($ty));
+            assert!(!(1 as $ty).is_zero(), stringify!($ty));
+            assert!(!<$ty>::MAX.is_zero(), stringify!($ty));
+            assert!(!<$ty>::MIN.is_zero() || <$ty>::MIN == 0, stringify!($ty));
+            assert!(Wrapping(0 as $ty).is_zero(), stringify!($ty));
+        )*
+    };
+}
+
+#[test]
+fn integers() {
+    check_ints!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+    assert!(!(-1_i32).is_zero());
+    assert!((Wrapping(u8::MAX) + Wrapping(1)).is_zero());
+    assert!(IsZero::is_zero(&&0_u8));
+}
+
+#[test]
+fn floats() {
+    assert!(0.0_f32.is_zero());
+    assert!((-0.0_f32).is_zero());
+    assert!(0.0_f64.is_zero());
+    assert!((-0.0_f64).is_zero());
+
+    assert!(!f32::MIN_POSITIVE.is_zero());
+    assert!(!f64::EPSILON.is_zero());
+    assert!(!f64::NAN.is_zero());
+    assert!(!f64::INFINITY.is_zero());
+}
+
+#[test]
+fn duration() {
+    assert!(Duration::ZERO.is_zero());
+    assert!(Duration::from_secs(0).is_zero());
+    assert!(!Duration::from_nanos(1).is_zero());
+}
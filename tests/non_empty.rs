@@ -0,0 +1,126 @@
+#![cfg(feature = "alloc")]
+
+extern crate alloc;
+use alloc::collections::BTreeMap;
+
+use optempty::{
+    non_empty::{EmptyError, NonEmptyBTreeMap, NonEmptyString, NonEmptyVec},
+    IntoNonEmpty, IsEmpty,
+};
+
+#[test]
+fn vec() {
+    assert_eq!(Err(EmptyError), NonEmptyVec::<&str>::try_from(vec![]));
+
+    let mut v = NonEmptyVec::try_from(vec!["b"]).unwrap();
+    assert_eq!(&"b", v.first());
+    assert_eq!(&"b", v.last());
+    assert_eq!(&"b", v.max());
+
+    v.push("c");
+    v.push("a");
+    assert_eq!(&"b", v.first());
+    assert_eq!(&"a", v.last());
+    assert_eq!(&"c", v.max());
+    assert_eq!(["b", "c", "a"], *v);
+    assert!(!IsEmpty::is_empty(&v));
+
+    assert_eq!(vec!["b", "c", "a"], v.into_inner());
+    assert_eq!(vec!["a"], Vec::from(NonEmptyVec::new("a")));
+}
+
+#[test]
+fn string() {
+    assert_eq!(Err(EmptyError), NonEmptyString::try_from(String::new()));
+    assert_eq!(Err(EmptyError), NonEmptyString::try_from(""));
+
+    let mut s = NonEmptyString::try_from("é").unwrap();
+    assert_eq!('é', s.first());
+    assert_eq!('é', s.last());
+    assert_eq!('é', s.max());
+
+    s.push_str("zà");
+    assert_eq!('é', s.first());
+    assert_eq!('à', s.last());
+    assert_eq!('é', s.max());
+    assert_eq!("ézà", &*s);
+    assert_eq!("ézà", s.to_string());
+    assert!(!IsEmpty::is_empty(&s));
+
+    assert_eq!(String::from("ézà"), s.into_inner());
+
+    assert_eq!("é", &*NonEmptyString::new('é'));
+}
+
+#[test]
+fn btree_map() {
+    assert_eq!(
+        Err(EmptyError),
+        NonEmptyBTreeMap::<&str, u32>::try_from(BTreeMap::new()),
+    );
+
+    let mut map = NonEmptyBTreeMap::new("b", 2);
+    assert_eq!((&"b", &2), map.first());
+    assert_eq!((&"b", &2), map.last());
+
+    map.insert("c", 3);
+    map.insert("a", 1);
+    assert_eq!((&"a", &1), map.first());
+    assert_eq!((&"c", &3), map.last());
+    assert_eq!((&"c", &3), map.max());
+    assert_eq!(Some(&2), map.get("b"));
+    assert!(!IsEmpty::is_empty(&map));
+
+    assert_eq!(
+        BTreeMap::from([("a", 1), ("b", 2), ("c", 3)]),
+        map.into_inner(),
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_map() {
+    use std::collections::HashMap;
+
+    use optempty::non_empty::NonEmptyHashMap;
+
+    assert_eq!(
+        Err(EmptyError),
+        NonEmptyHashMap::<&str, u32>::try_from(HashMap::new()),
+    );
+
+    assert_eq!(
+        NonEmptyHashMap::try_from(HashMap::from([("b", 2)])),
+        Ok(NonEmptyHashMap::new("b", 2)),
+    );
+
+    let mut map = NonEmptyHashMap::new("b", 2);
+    assert_eq!((&"b", &2), map.first());
+    assert_eq!((&"b", &2), map.last());
+
+    map.insert("c", 3);
+    map.insert("a", 1);
+    assert_eq!((&"c", &3), map.max());
+    assert_eq!(Some(&2), map.get("b"));
+    assert!(!IsEmpty::is_empty(&map));
+}
+
+#[test]
+fn into_non_empty() {
+    assert_eq!(None, Vec::<u32>::new().into_non_empty());
+    assert_eq!(Some(NonEmptyVec::new(1)), Some(vec![1]).into_non_empty(),);
+    assert_eq!(None, Some(Vec::<u32>::new()).into_non_empty());
+    assert_eq!(None, Option::<Vec<u32>>::None.into_non_empty());
+
+    assert_eq!(None, Some(String::new()).into_non_empty());
+    assert_eq!(
+        Some(NonEmptyString::try_from("a").unwrap()),
+        Some(String::from("a")).into_non_empty(),
+    );
+
+    assert_eq!(None, Some(BTreeMap::<u32, u32>::new()).into_non_empty());
+    assert_eq!(
+        Some(NonEmptyBTreeMap::new(1, 2)),
+        Some(BTreeMap::from([(1, 2)])).into_non_empty(),
+    );
+}
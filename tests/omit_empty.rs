@@ -0,0 +1,239 @@
+#![cfg(all(feature = "derive", feature = "serde", feature = "alloc"))]
+
+use std::collections::BTreeMap;
+
+use optempty::{omit_empty, IsEmpty};
+use serde::{Deserialize, Serialize};
+
+#[omit_empty]
+#[derive(Debug, Default, Serialize)]
+struct Basic {
+    string: String,
+    option: Option<String>,
+    vec: Vec<u32>,
+    map: BTreeMap<String, u32>,
+    count: u32,
+    #[omit_empty(keep)]
+    kept: Vec<u32>,
+}
+
+#[omit_empty(default)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct RoundTrip {
+    name: String,
+    nickname: Option<String>,
+    aliases: Vec<String>,
+}
+
+fn is_zero(n: &u32) -> bool {
+    *n == 0
+}
+
+#[omit_empty]
+#[derive(Debug, Default, Serialize)]
+struct Existing {
+    #[serde(skip_serializing_if = "is_zero")]
+    count: u32,
+    #[serde(skip)]
+    skipped: String,
+    #[serde(rename = "renamed")]
+    r#type: String,
+}
+
+#[derive(Debug, Default, Serialize, IsEmpty)]
+struct Custom {
+    names: Vec<String>,
+}
+
+#[omit_empty]
+#[derive(Debug, Default, Serialize)]
+struct Nested<'a> {
+    custom: Custom,
+    borrowed: &'a str,
+    slice: &'a [u32],
+}
+
+#[omit_empty]
+#[derive(Debug, Serialize)]
+struct Generic<T> {
+    items: Vec<T>,
+    item: T,
+}
+
+#[omit_empty]
+#[derive(Debug, Default, Serialize)]
+struct NotIsEmpty {
+    count: Option<u32>,
+    flag: Option<bool>,
+    unit: (),
+    custom: Option<Custom>,
+    #[serde(serialize_with = "serialize_count")]
+    with: Option<u32>,
+}
+
+fn serialize_count<S: serde::Serializer>(count: &Option<u32>, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u32(count.unwrap_or(0))
+}
+
+#[omit_empty]
+#[derive(Debug, Serialize)]
+struct Tuple(String, Vec<u32>);
+
+#[omit_empty]
+#[derive(Debug, Serialize)]
+enum Kind {
+    Unit,
+    Tuple(String),
+    Struct {
+        name: String,
+        #[omit_empty(keep)]
+        tags: Vec<String>,
+    },
+}
+
+// Joining the variant and field names would give both fields the same
+// helper.
+#[omit_empty]
+#[derive(Debug, Serialize)]
+#[allow(non_camel_case_types)]
+enum SimilarNames {
+    A_b { c: String },
+    A { b_c: String },
+}
+
+fn json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap()
+}
+
+#[test]
+fn basic() {
+    assert_eq!(r#"{"count":0,"kept":[]}"#, json(&Basic::default()));
+
+    let basic = Basic {
+        option: Some(String::new()),
+        ..Basic::default()
+    };
+    assert_eq!(r#"{"count":0,"kept":[]}"#, json(&basic));
+
+    let basic = Basic {
+        string: "a".into(),
+        option: Some("b".into()),
+        vec: vec![1],
+        map: BTreeMap::from([("c".into(), 2)]),
+        count: 3,
+        kept: vec![4],
+    };
+    assert_eq!(
+        r#"{"string":"a","option":"b","vec":[1],"map":{"c":2},"count":3,"kept":[4]}"#,
+        json(&basic),
+    );
+}
+
+#[test]
+fn round_trip() {
+    let empty = RoundTrip::default();
+    assert_eq!("{}", json(&empty));
+    assert_eq!(empty, serde_json::from_str("{}").unwrap());
+
+    let full = RoundTrip {
+        name: "a".into(),
+        nickname: Some("b".into()),
+        aliases: vec!["c".into()],
+    };
+    assert_eq!(full, serde_json::from_str(&json(&full)).unwrap());
+}
+
+#[test]
+fn existing_serde_attributes() {
+    assert_eq!("{}", json(&Existing::default()));
+
+    let existing = Existing {
+        count: 1,
+        skipped: "a".into(),
+        r#type: "b".into(),
+    };
+    assert_eq!(r#"{"count":1,"renamed":"b"}"#, json(&existing));
+    assert_eq!("a", existing.skipped);
+}
+
+#[test]
+fn nested() {
+    assert_eq!("{}", json(&Nested::default()));
+
+    let nested = Nested {
+        custom: Custom {
+            names: vec!["a".into()],
+        },
+        borrowed: "b",
+        slice: &[1],
+    };
+    assert_eq!(
+        r#"{"custom":{"names":["a"]},"borrowed":"b","slice":[1]}"#,
+        json(&nested),
+    );
+}
+
+#[test]
+fn not_is_empty() {
+    // `None` is left out even where `T` doesn't implement `IsEmpty`.
+    assert_eq!(r#"{"with":0}"#, json(&NotIsEmpty::default()));
+
+    let not_is_empty = NotIsEmpty {
+        count: Some(0),
+        flag: Some(false),
+        unit: (),
+        custom: Some(Custom::default()),
+        with: Some(1),
+    };
+    // `Option<Custom>` is `IsEmpty`, so `Some` of an empty `Custom` is left
+    // out too.
+    assert_eq!(r#"{"count":0,"flag":false,"with":1}"#, json(&not_is_empty));
+}
+
+#[test]
+fn generic() {
+    // A bare `T` is checked by how it serializes.
+    let generic = Generic {
+        items: Vec::<String>::new(),
+        item: String::new(),
+    };
+    assert_eq!("{}", json(&generic));
+
+    let generic = Generic {
+        items: vec![None],
+        item: None::<u32>,
+    };
+    assert_eq!(r#"{"items":[null]}"#, json(&generic));
+
+    let generic = Generic {
+        items: vec![],
+        item: 0,
+    };
+    assert_eq!(r#"{"item":0}"#, json(&generic));
+}
+
+#[test]
+fn tuple_struct() {
+    assert_eq!(r#"["",[]]"#, json(&Tuple(String::new(), vec![])));
+}
+
+#[test]
+fn enums() {
+    assert_eq!(r#""Unit""#, json(&Kind::Unit));
+    assert_eq!(r#"{"Tuple":""}"#, json(&Kind::Tuple(String::new())));
+
+    let kind = Kind::Struct {
+        name: String::new(),
+        tags: vec![],
+    };
+    assert_eq!(r#"{"Struct":{"tags":[]}}"#, json(&kind));
+}
+
+#[test]
+fn similar_names() {
+    let a_b = SimilarNames::A_b { c: String::new() };
+    assert_eq!(r#"{"A_b":{}}"#, json(&a_b));
+
+    let a = SimilarNames::A { b_c: "d".into() };
+    assert_eq!(r#"{"A":{"b_c":"d"}}"#, json(&a));
+}
@@ -0,0 +1,39 @@
+use optempty::ParseNonEmpty;
+
+#[test]
+fn str() {
+    assert_eq!(Ok(None), "".parse_non_empty::<u32>());
+    assert_eq!(Ok(Some(7)), "7".parse_non_empty::<u32>());
+    assert!("x".parse_non_empty::<u32>().is_err());
+    assert!(" 7".parse_non_empty::<u32>().is_err());
+
+    assert_eq!(Ok(None), "".parse_non_blank::<u32>());
+    assert_eq!(Ok(None), " \t\n".parse_non_blank::<u32>());
+    assert_eq!(Ok(Some(7)), " 7\n".parse_non_blank::<u32>());
+    assert!(" x ".parse_non_blank::<u32>().is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn string() {
+    extern crate alloc;
+    use alloc::string::String;
+
+    assert_eq!(Ok(None), String::new().parse_non_empty::<f64>());
+    assert_eq!(Ok(Some(1.5)), String::from("1.5").parse_non_empty::<f64>());
+    assert_eq!(Ok(None), String::from("  ").parse_non_blank::<f64>());
+}
+
+#[test]
+fn option() {
+    let none: Option<&str> = None;
+    assert_eq!(Ok(None), none.parse_non_empty::<bool>());
+    assert_eq!(Ok(None), none.parse_non_blank::<bool>());
+
+    assert_eq!(Ok(None), Some("").parse_non_empty::<bool>());
+    assert_eq!(Ok(Some(true)), Some("true").parse_non_empty::<bool>());
+    assert!(Some(" ").parse_non_empty::<bool>().is_err());
+
+    assert_eq!(Ok(None), Some(" ").parse_non_blank::<bool>());
+    assert_eq!(Ok(Some(false)), Some(" false ").parse_non_blank::<bool>());
+}
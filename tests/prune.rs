@@ -0,0 +1,132 @@
+#![cfg(feature = "serdejson")]
+
+use optempty::prune::{PruneEmpty, PruneOptions};
+use serde_json::{json, Map, Value};
+
+fn pruned(mut value: Value, options: &PruneOptions) -> Value {
+    value.prune_empty_with(options);
+    value
+}
+
+#[test]
+fn removes_empty_members() {
+    let mut doc = json!({
+        "null": null,
+        "string": "",
+        "array": [],
+        "object": {},
+        "zero": 0,
+        "false": false,
+        "a": "a",
+    });
+    doc.prune_empty();
+    assert_eq!(json!({"zero": 0, "false": false, "a": "a"}), doc);
+}
+
+#[test]
+fn removes_array_elements() {
+    let mut doc = json!(["", null, 1, [], {}, "a", [null, ""]]);
+    doc.prune_empty();
+    assert_eq!(json!([1, "a"]), doc);
+}
+
+#[test]
+fn bottom_up() {
+    let mut doc = json!({
+        "a": {
+            "b": {
+                "c": [{"d": ""}, [null]],
+            },
+            "e": 1,
+        },
+        "f": {"g": {"h": {}}},
+    });
+    doc.prune_empty();
+    assert_eq!(json!({"a": {"e": 1}}), doc);
+}
+
+#[test]
+fn root_becomes_empty() {
+    let mut doc = json!({"a": {"b": [""]}});
+    doc.prune_empty();
+    assert_eq!(json!({}), doc);
+
+    let mut doc = json!([[], {}]);
+    doc.prune_empty();
+    assert_eq!(json!([]), doc);
+
+    let mut doc = json!("");
+    doc.prune_empty();
+    assert_eq!(json!(""), doc);
+}
+
+#[test]
+fn keep_null() {
+    let options = PruneOptions::new().keep_null(true);
+
+    assert_eq!(
+        json!({"a": null, "c": [null]}),
+        pruned(
+            json!({"a": null, "b": "", "c": [null, ""], "d": {}}),
+            &options
+        ),
+    );
+}
+
+#[test]
+fn keep_empty_arrays() {
+    let options = PruneOptions::new().keep_empty_arrays(true);
+
+    assert_eq!(
+        json!({"a": [], "b": [], "d": [[]]}),
+        pruned(
+            json!({"a": [], "b": [""], "c": {"x": null}, "d": [[], null]}),
+            &options,
+        ),
+    );
+}
+
+#[test]
+fn keep_path() {
+    let options = PruneOptions::new()
+        .keep_path("/a")
+        .keep_path("/b/1")
+        .keep_path("/c/d~1e")
+        .keep_path("/f/g~0h");
+
+    assert_eq!(
+        json!({
+            "a": {"x": ""},
+            "b": [null],
+            "c": {"d/e": ""},
+            "f": {"g~h": []},
+        }),
+        pruned(
+            json!({
+                "a": {"x": ""},
+                "b": ["", null, ""],
+                "c": {"d/e": "", "d": {"e": ""}},
+                "f": {"g~h": [], "gh": []},
+                "i": "",
+            }),
+            &options,
+        ),
+    );
+}
+
+#[test]
+fn keep_root() {
+    let doc = json!({"a": ""});
+    assert_eq!(doc.clone(), pruned(doc, &PruneOptions::new().keep_path("")));
+}
+
+#[test]
+fn map() {
+    let mut map = Map::new();
+    map.insert("a".into(), json!(""));
+    map.insert("b".into(), json!({"c": [null]}));
+    map.insert("d".into(), json!(1));
+
+    map.prune_empty();
+    assert_eq!(Some(&json!({"d": 1})), Some(&Value::Object(map)));
+}
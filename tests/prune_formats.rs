@@ -0,0 +1,236 @@
+#![cfg(any(feature = "toml", feature = "serde_yaml", feature = "ciborium"))]
+
+#[cfg(feature = "toml")]
+mod toml_value {
+    use optempty::prune::{PruneEmpty, PruneOptions};
+    use toml::{Table, Value};
+
+    fn pruned(toml: &str, options: &PruneOptions) -> Table {
+        let mut table: Table = toml::from_str(toml).unwrap();
+        table.prune_empty_with(options);
+        table
+    }
+
+    #[test]
+    fn removes_empty_members() {
+        let table = pruned(
+            r#"
+            name = "Zoe"
+            nickname = ""
+            tags = []
+            lines = ["", "a", []]
+
+            [address]
+            street = ""
+            lines = [""]
+
+            [[items]]
+            name = ""
+            "#,
+            &PruneOptions::new(),
+        );
+        assert_eq!(
+            toml::from_str::<Table>(
+                r#"
+                name = "Zoe"
+                lines = ["a"]
+                "#
+            )
+            .unwrap(),
+            table,
+        );
+    }
+
+    #[test]
+    fn keep_empty_arrays() {
+        let table = pruned(
+            r#"
+            tags = []
+            lines = [""]
+            name = ""
+            "#,
+            &PruneOptions::new().keep_empty_arrays(true),
+        );
+        assert_eq!(
+            toml::from_str::<Table>("tags = []\nlines = []").unwrap(),
+            table
+        );
+    }
+
+    #[test]
+    fn keep_path() {
+        let table = pruned(
+            r#"
+            a = { b = "", c = "" }
+            d = ["", "", 1]
+            "#,
+            &PruneOptions::new().keep_path("/a/b").keep_path("/d/1"),
+        );
+        assert_eq!(
+            toml::from_str::<Table>(r#"a = { b = "" }"#)
+                .unwrap()
+                .into_iter()
+                .chain([(
+                    "d".to_string(),
+                    Value::Array(vec![Value::String(String::new()), Value::Integer(1)])
+                )])
+                .collect::<Table>(),
+            table,
+        );
+    }
+
+    #[test]
+    fn value() {
+        let mut value = Value::Table(toml::from_str(r#"a = { b = [""] }"#).unwrap());
+        value.prune_empty();
+        assert_eq!(Value::Table(Table::new()), value);
+    }
+}
+
+#[cfg(feature = "serde_yaml")]
+mod serde_yaml_value {
+    use optempty::prune::{PruneEmpty, PruneOptions};
+    use serde_yaml::{Mapping, Value};
+
+    fn yaml(yaml: &str) -> Value {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn pruned(source: &str, options: &PruneOptions) -> Value {
+        let mut value = yaml(source);
+        value.prune_empty_with(options);
+        value
+    }
+
+    #[test]
+    fn removes_empty_members() {
+        let value = pruned(
+            r#"
+            name: Zoe
+            nickname: ''
+            tags: []
+            extra: ~
+            address:
+              street: ~
+              lines: ['']
+            items: [~, '', a, {}]
+            "#,
+            &PruneOptions::new(),
+        );
+        assert_eq!(yaml("{name: Zoe, items: [a]}"), value);
+    }
+
+    #[test]
+    fn keep_null() {
+        let value = pruned(
+            "{a: ~, b: '', c: [~, '']}",
+            &PruneOptions::new().keep_null(true),
+        );
+        assert_eq!(yaml("{a: ~, c: [~]}"), value);
+    }
+
+    #[test]
+    fn tagged_values_are_kept() {
+        let value = pruned("{a: !tag {b: ''}, c: !tag ''}", &PruneOptions::new());
+        assert_eq!(yaml("{a: !tag {}, c: !tag ''}"), value);
+    }
+
+    #[test]
+    fn keep_path() {
+        let options = PruneOptions::new()
+            .keep_path("/a/b")
+            .keep_path("/1")
+            .keep_path("/true");
+        let value = pruned(
+            "{a: {b: '', c: ''}, 1: '', true: '', 2: '', [x]: {b: ''}}",
+            &options,
+        );
+        assert_eq!(yaml("{a: {b: ''}, 1: '', true: ''}"), value);
+    }
+
+    #[test]
+    fn mapping() {
+        let mut mapping: Mapping = serde_yaml::from_str("{a: '', b: [~], c: 1}").unwrap();
+        mapping.prune_empty();
+        assert_eq!(serde_yaml::from_str::<Mapping>("{c: 1}").unwrap(), mapping);
+    }
+}
+
+#[cfg(feature = "ciborium")]
+mod ciborium_value {
+    use ciborium::Value;
+    use optempty::prune::{PruneEmpty, PruneOptions};
+
+    fn text(s: &str) -> Value {
+        Value::Text(s.into())
+    }
+
+    fn map(entries: Vec<(Value, Value)>) -> Value {
+        Value::Map(entries)
+    }
+
+    fn pruned(mut value: Value, options: &PruneOptions) -> Value {
+        value.prune_empty_with(options);
+        value
+    }
+
+    #[test]
+    fn removes_empty_members() {
+        let value = map(vec![
+            (text("name"), text("Zoe")),
+            (text("nickname"), text("")),
+            (text("photo"), Value::Bytes(vec![])),
+            (text("extra"), Value::Null),
+            (
+                text("address"),
+                map(vec![(text("lines"), Value::Array(vec![text("")]))]),
+            ),
+            (
+                text("items"),
+                Value::Array(vec![Value::Null, text("a"), map(vec![])]),
+            ),
+        ]);
+        assert_eq!(
+            map(vec![
+                (text("name"), text("Zoe")),
+                (text("items"), Value::Array(vec![text("a")])),
+            ]),
+            pruned(value, &PruneOptions::new()),
+        );
+    }
+
+    #[test]
+    fn tagged_values_are_kept() {
+        let value = Value::Array(vec![Value::Tag(
+            1,
+            Box::new(map(vec![(text("a"), text(""))])),
+        )]);
+        assert_eq!(
+            Value::Array(vec![Value::Tag(1, Box::new(map(vec![])))]),
+            pruned(value, &PruneOptions::new()),
+        );
+    }
+
+    #[test]
+    fn keep_path() {
+        let options = PruneOptions::new()
+            .keep_path("/a")
+            .keep_path("/-1")
+            .keep_path("/false");
+        let value = map(vec![
+            (text("a"), text("")),
+            (Value::Integer((-1).into()), text("")),
+            (Value::Bool(false), text("")),
+            (Value::Bytes(vec![]), text("")),
+            (Value::Null, map(vec![(text("a"), text(""))])),
+        ]);
+        assert_eq!(
+            map(vec![
+                (text("a"), text("")),
+                (Value::Integer((-1).into()), text("")),
+                (Value::Bool(false), text("")),
+            ]),
+            pruned(value, &options),
+        );
+    }
+}
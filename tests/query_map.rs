@@ -0,0 +1,60 @@
+#![cfg(all(feature = "querymap", feature = "std"))]
+
+use std::collections::HashMap;
+
+use optempty::{query_map::QueryMapNonEmpty, IsEmpty};
+use query_map::QueryMap;
+
+fn query(s: &str) -> QueryMap {
+    s.parse().unwrap()
+}
+
+#[test]
+fn first_non_empty() {
+    let q = query("a=&a=1&a=2&b=&c=3");
+    assert_eq!(Some("1"), q.first_non_empty("a"));
+    assert_eq!(None, q.first_non_empty("b"));
+    assert_eq!(Some("3"), q.first_non_empty("c"));
+    assert_eq!(None, q.first_non_empty("d"));
+}
+
+#[test]
+fn all_non_empty() {
+    let q = query("a=1&a=&a=2&b=&b=&c=3");
+    assert_eq!(Some(vec!["1", "2"]), q.all_non_empty("a"));
+    assert_eq!(None, q.all_non_empty("b"));
+    assert_eq!(Some(vec!["3"]), q.all_non_empty("c"));
+    assert_eq!(None, q.all_non_empty("d"));
+}
+
+#[test]
+fn without_empty() {
+    let q = query("a=1&a=&a=2&b=&b=&c=3&d").without_empty();
+    assert_eq!(Some(vec!["1", "2"]), q.all("a"));
+    assert_eq!(None, q.all("b"));
+    assert_eq!(Some(vec!["3"]), q.all("c"));
+    assert_eq!(None, q.all("d"));
+
+    assert!(IsEmpty::is_empty(&query("a=&b=").without_empty()));
+    assert!(IsEmpty::is_empty(&QueryMap::default().without_empty()));
+
+    let q = QueryMap::from(HashMap::from([
+        ("a".to_string(), String::new()),
+        ("b".to_string(), "1".to_string()),
+    ]));
+    assert_eq!(query("b=1"), q.without_empty());
+}
+
+#[test]
+fn to_non_empty_query_string() {
+    assert_eq!("", query("a=&b=").to_non_empty_query_string());
+    assert_eq!("", QueryMap::default().to_non_empty_query_string());
+    assert_eq!(
+        "a=1&a=2",
+        query("a=1&b=&a=&a=2").to_non_empty_query_string()
+    );
+    assert_eq!(
+        "a=x+y%26z",
+        query("a=x+y%26z&b=").to_non_empty_query_string()
+    );
+}
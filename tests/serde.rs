@@ -0,0 +1,612 @@
+#![cfg(all(feature = "serde", feature = "alloc"))]
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct Skip {
+    #[serde(skip_serializing_if = "optempty::serde::is_empty")]
+    string: String,
+    #[serde(skip_serializing_if = "optempty::serde::is_empty")]
+    vec: Vec<u32>,
+    #[serde(skip_serializing_if = "optempty::serde::is_empty")]
+    map: BTreeMap<String, u32>,
+    #[serde(skip_serializing_if = "optempty::serde::is_empty")]
+    option: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct AsNone {
+    #[serde(default, with = "optempty::serde::empty_as_none")]
+    string: Option<String>,
+    #[serde(default, with = "optempty::serde::empty_as_none")]
+    vec: Option<Vec<u32>>,
+    #[serde(default, with = "optempty::serde::empty_as_none")]
+    map: Option<BTreeMap<String, u32>>,
+}
+
+#[test]
+fn skip_serializing_if() {
+    assert_eq!("{}", serde_json::to_string(&Skip::default()).unwrap());
+
+    let skip = Skip {
+        option: Some(String::new()),
+        ..Skip::default()
+    };
+    assert_eq!("{}", serde_json::to_string(&skip).unwrap());
+
+    let skip = Skip {
+        string: "a".into(),
+        vec: vec![1],
+        map: BTreeMap::from([("b".into(), 2)]),
+        option: Some("c".into()),
+    };
+    assert_eq!(
+        r#"{"string":"a","vec":[1],"map":{"b":2},"option":"c"}"#,
+        serde_json::to_string(&skip).unwrap(),
+    );
+}
+
+#[test]
+fn empty_as_none_deserialize() {
+    let empty: AsNone = serde_json::from_str(r#"{"string":"","vec":[],"map":{}}"#).unwrap();
+    assert_eq!(AsNone::default(), empty);
+
+    let null: AsNone = serde_json::from_str(r#"{"string":null,"vec":null,"map":null}"#).unwrap();
+    assert_eq!(AsNone::default(), null);
+
+    let missing: AsNone = serde_json::from_str("{}").unwrap();
+    assert_eq!(AsNone::default(), missing);
+
+    let full: AsNone = serde_json::from_str(r#"{"string":"a","vec":[1],"map":{"b":2}}"#).unwrap();
+    assert_eq!(
+        AsNone {
+            string: Some("a".into()),
+            vec: Some(vec![1]),
+            map: Some(BTreeMap::from([("b".into(), 2)])),
+        },
+        full,
+    );
+}
+
+#[test]
+fn empty_as_none_serialize() {
+    assert_eq!(
+        r#"{"string":"","vec":[],"map":{}}"#,
+        serde_json::to_string(&AsNone::default()).unwrap(),
+    );
+
+    let full = AsNone {
+        string: Some("a".into()),
+        vec: Some(vec![1]),
+        map: Some(BTreeMap::from([("b".into(), 2)])),
+    };
+    let json = serde_json::to_string(&full).unwrap();
+    assert_eq!(r#"{"string":"a","vec":[1],"map":{"b":2}}"#, json);
+    assert_eq!(full, serde_json::from_str(&json).unwrap());
+}
+
+mod omit_empty_de {
+    use std::collections::BTreeMap;
+
+    use optempty::serde::de::OmitEmpty;
+    use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        name: Option<String>,
+        tags: Option<Vec<String>>,
+        meta: Option<BTreeMap<String, Option<u32>>>,
+        child: Option<Box<Item>>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Defaults {
+        #[serde(default = "unknown")]
+        city: String,
+        #[serde(default)]
+        tags: Vec<String>,
+    }
+
+    fn unknown() -> String {
+        "unknown".into()
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Required {
+        name: String,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Kind {
+        Plain,
+        Named(String),
+        Full { items: Option<Vec<Item>> },
+    }
+
+    fn from_str<T: DeserializeOwned>(json: &str) -> T {
+        let mut de = serde_json::Deserializer::from_str(json);
+        let value = T::deserialize(OmitEmpty::new(&mut de)).unwrap();
+        de.end().unwrap();
+        value
+    }
+
+    fn from_reader<T: DeserializeOwned>(json: &str) -> T {
+        let mut de = serde_json::Deserializer::from_reader(json.as_bytes());
+        let value = T::deserialize(OmitEmpty::new(&mut de)).unwrap();
+        de.end().unwrap();
+        value
+    }
+
+    #[test]
+    fn empty_is_missing() {
+        let json = r#"{"name":"","tags":[],"meta":{},"child":null}"#;
+        assert_eq!(Item::default(), from_str(json));
+        assert_eq!(Item::default(), from_reader(json));
+        assert_eq!(Item::default(), from_str("{}"));
+
+        // Left out before its type is checked.
+        assert_eq!(Item::default(), from_str(r#"{"name":[]}"#));
+    }
+
+    #[test]
+    fn defaults() {
+        let defaults: Defaults = from_str(r#"{"city":"","tags":[]}"#);
+        assert_eq!(
+            Defaults {
+                city: "unknown".into(),
+                tags: vec![],
+            },
+            defaults,
+        );
+
+        let mut de = serde_json::Deserializer::from_str(r#"{"name":""}"#);
+        let err = Required::deserialize(OmitEmpty::new(&mut de)).unwrap_err();
+        assert!(err.to_string().contains("missing field `name`"), "{err}");
+    }
+
+    #[test]
+    fn non_empty_is_kept() {
+        let json = r#"{"name":"a","tags":["b","c"],"meta":{"d":1,"e":2},"child":{"name":"f"}}"#;
+        let expected = Item {
+            name: Some("a".into()),
+            tags: Some(vec!["b".into(), "c".into()]),
+            meta: Some(BTreeMap::from([
+                ("d".into(), Some(1)),
+                ("e".into(), Some(2)),
+            ])),
+            child: Some(Box::new(Item {
+                name: Some("f".into()),
+                ..Item::default()
+            })),
+        };
+        assert_eq!(expected, from_str(json));
+        assert_eq!(expected, from_reader(json));
+    }
+
+    #[test]
+    fn nested() {
+        let json = r#"{"child":{"name":"","child":{"tags":[],"name":"a"}}}"#;
+        let expected = Item {
+            child: Some(Box::new(Item {
+                child: Some(Box::new(Item {
+                    name: Some("a".into()),
+                    ..Item::default()
+                })),
+                ..Item::default()
+            })),
+            ..Item::default()
+        };
+        assert_eq!(expected, from_str(json));
+        assert_eq!(expected, from_reader(json));
+    }
+
+    #[test]
+    fn maps() {
+        let map: BTreeMap<String, String> = from_str(r#"{"a":"","b":"c","d":null}"#);
+        assert_eq!(BTreeMap::from([("b".into(), "c".into())]), map);
+
+        let map: BTreeMap<u32, bool> = from_str(r#"{"1":true,"2":null}"#);
+        assert_eq!(BTreeMap::from([(1, true)]), map);
+
+        // Buffered with the rest of the value.
+        let item: Item = from_str(r#"{"meta":{"1":null,"2":3}}"#);
+        assert_eq!(Some(BTreeMap::from([("2".into(), Some(3))])), item.meta);
+        let items: BTreeMap<String, BTreeMap<u32, u32>> = from_str(r#"{"a":{"1":2,"3":null}}"#);
+        assert_eq!(
+            BTreeMap::from([("a".into(), BTreeMap::from([(1, 2)]))]),
+            items
+        );
+    }
+
+    #[test]
+    fn emptiness_is_decided_as_read() {
+        // `meta` had an entry, so it isn't empty, even though that entry was
+        // left out.
+        let item: Item = from_str(r#"{"meta":{"a":null}}"#);
+        assert_eq!(Some(BTreeMap::new()), item.meta);
+
+        let item: Item = from_str(r#"{"child":{"name":""}}"#);
+        assert_eq!(Some(Box::new(Item::default())), item.child);
+    }
+
+    #[test]
+    fn unit_is_empty() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Unit {
+            unit: Option<()>,
+        }
+
+        let unit: Unit = from_str(r#"{"unit":null}"#);
+        assert_eq!(Unit { unit: None }, unit);
+    }
+
+    #[test]
+    fn sequence_elements_are_kept() {
+        let values: Vec<Option<String>> = from_str(r#"["", null, "a"]"#);
+        assert_eq!(vec![Some(String::new()), None, Some("a".into())], values);
+
+        let items: Vec<Item> = from_str(r#"[{"name":"","tags":["a"]},{"name":""}]"#);
+        assert_eq!(
+            vec![
+                Item {
+                    tags: Some(vec!["a".into()]),
+                    ..Item::default()
+                },
+                Item::default(),
+            ],
+            items,
+        );
+    }
+
+    #[test]
+    fn enums() {
+        let kinds: Vec<Kind> = from_str(
+            r#"["Plain", {"Named":""}, {"Full":{"items":[]}}, {"Full":{"items":[{"name":""}]}}]"#,
+        );
+        assert_eq!(
+            vec![
+                Kind::Plain,
+                Kind::Named(String::new()),
+                Kind::Full { items: None },
+                Kind::Full {
+                    items: Some(vec![Item::default()]),
+                },
+            ],
+            kinds,
+        );
+
+        let kinds: BTreeMap<String, Kind> =
+            from_str(r#"{"a":{"Named":"b"},"c":{"Full":{"items":[]}}}"#);
+        assert_eq!(
+            BTreeMap::from([
+                ("a".into(), Kind::Named("b".into())),
+                ("c".into(), Kind::Full { items: None }),
+            ]),
+            kinds,
+        );
+    }
+
+    #[cfg(feature = "serde_yaml")]
+    #[test]
+    fn yaml_tagged_enums() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Tagged {
+            kind: Kind,
+            name: Option<String>,
+            kinds: BTreeMap<String, Kind>,
+        }
+
+        let yaml = "
+kind: !Named a
+name: ''
+kinds:
+  b: !Plain
+  c: !Full
+    items: []
+  d: !Full
+    items: [{name: e}]
+";
+        let tagged = Tagged::deserialize(OmitEmpty::new(serde_yaml::Deserializer::from_str(yaml)));
+        assert_eq!(
+            Tagged {
+                kind: Kind::Named("a".into()),
+                name: None,
+                kinds: BTreeMap::from([
+                    ("b".into(), Kind::Plain),
+                    ("c".into(), Kind::Full { items: None }),
+                    (
+                        "d".into(),
+                        Kind::Full {
+                            items: Some(vec![Item {
+                                name: Some("e".into()),
+                                ..Item::default()
+                            }]),
+                        },
+                    ),
+                ]),
+            },
+            tagged.unwrap(),
+        );
+    }
+
+    #[test]
+    fn cbor() {
+        #[derive(Serialize)]
+        struct Raw {
+            name: &'static str,
+            tags: Vec<String>,
+            meta: BTreeMap<&'static str, Option<u32>>,
+            child: Item,
+        }
+
+        let raw = Raw {
+            name: "",
+            tags: vec![],
+            meta: BTreeMap::from([("a", None), ("b", Some(1))]),
+            child: Item {
+                name: Some(String::new()),
+                tags: Some(vec!["c".into()]),
+                ..Item::default()
+            },
+        };
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&raw, &mut cbor).unwrap();
+
+        // ciborium's deserializer isn't public, so wrap it from inside a
+        // `Deserialize` impl.
+        struct Omit<T>(T);
+
+        impl<'de, T: Deserialize<'de>> Deserialize<'de> for Omit<T> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                T::deserialize(OmitEmpty::new(deserializer)).map(Omit)
+            }
+        }
+
+        let Omit(item) = ciborium::from_reader::<Omit<Item>, _>(cbor.as_slice()).unwrap();
+        assert_eq!(
+            Item {
+                meta: Some(BTreeMap::from([("b".into(), Some(1))])),
+                child: Some(Box::new(Item {
+                    tags: Some(vec!["c".into()]),
+                    ..Item::default()
+                })),
+                ..Item::default()
+            },
+            item,
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let mut de = serde_json::Deserializer::from_str(r#"{"tags":["a",1]}"#);
+        assert!(Item::deserialize(OmitEmpty::new(&mut de)).is_err());
+
+        let mut de = serde_json::Deserializer::from_str(r#"{"name":1}"#);
+        assert!(Item::deserialize(OmitEmpty::new(&mut de)).is_err());
+    }
+}
+
+mod omit_empty_ser {
+    use std::collections::{BTreeMap, HashMap};
+
+    use optempty::serde::OmitEmpty;
+    use serde::Serialize;
+
+    #[derive(Default, Serialize)]
+    struct Item {
+        name: String,
+        nickname: Option<String>,
+        tags: Vec<String>,
+        meta: BTreeMap<String, String>,
+        bytes: bytes::Bytes,
+        count: u32,
+        child: Option<Box<Item>>,
+    }
+
+    mod bytes {
+        use serde::{Serialize, Serializer};
+
+        #[derive(Default)]
+        pub struct Bytes(pub Vec<u8>);
+
+        impl Serialize for Bytes {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_bytes(&self.0)
+            }
+        }
+    }
+
+    #[derive(Serialize)]
+    struct Newtype(String);
+
+    #[derive(Serialize)]
+    struct Wrapper {
+        name: Newtype,
+        empty_struct: Empty,
+        unit: (),
+        pair: (String, String),
+        items: Vec<Option<String>>,
+    }
+
+    #[derive(Serialize)]
+    struct Empty {}
+
+    #[derive(Serialize)]
+    enum Kind {
+        Full { name: String, tags: Vec<u32> },
+        Named(Item),
+    }
+
+    fn json<T: Serialize>(value: &T) -> String {
+        serde_json::to_string(&OmitEmpty(value)).unwrap()
+    }
+
+    #[test]
+    fn empty_fields_are_omitted() {
+        assert_eq!(r#"{"count":0}"#, json(&Item::default()));
+
+        let item = Item {
+            nickname: Some(String::new()),
+            child: Some(Box::default()),
+            ..Item::default()
+        };
+        assert_eq!(r#"{"count":0,"child":{"count":0}}"#, json(&item));
+    }
+
+    #[test]
+    fn non_empty_fields_are_kept() {
+        let item = Item {
+            name: "a".into(),
+            nickname: Some("b".into()),
+            tags: vec!["c".into()],
+            meta: BTreeMap::from([("d".into(), "e".into()), ("f".into(), "".into())]),
+            bytes: bytes::Bytes(vec![1]),
+            count: 2,
+            child: None,
+        };
+        assert_eq!(
+            r#"{"name":"a","nickname":"b","tags":["c"],"meta":{"d":"e"},"bytes":[1],"count":2}"#,
+            json(&item),
+        );
+    }
+
+    #[test]
+    fn maps() {
+        let map = HashMap::from([("a", vec![]), ("b", vec![1])]);
+        assert_eq!(r#"{"b":[1]}"#, json(&map));
+
+        let map: BTreeMap<&str, Option<&str>> = BTreeMap::from([("a", None), ("b", Some(""))]);
+        assert_eq!("{}", json(&map));
+    }
+
+    #[test]
+    fn other_values() {
+        let wrapper = Wrapper {
+            name: Newtype(String::new()),
+            empty_struct: Empty {},
+            unit: (),
+            pair: (String::new(), String::new()),
+            items: vec![None, Some(String::new()), Some("a".into())],
+        };
+        assert_eq!(
+            r#"{"empty_struct":{},"pair":["",""],"items":[null,"","a"]}"#,
+            json(&wrapper),
+        );
+    }
+
+    #[test]
+    fn enums() {
+        let kind = Kind::Full {
+            name: String::new(),
+            tags: vec![1],
+        };
+        assert_eq!(r#"{"Full":{"tags":[1]}}"#, json(&kind));
+
+        let kind = Kind::Named(Item::default());
+        assert_eq!(r#"{"Named":{"count":0}}"#, json(&kind));
+    }
+
+    #[test]
+    fn sequences() {
+        let items = vec![Item::default(), Item::default()];
+        assert_eq!(r#"[{"count":0},{"count":0}]"#, json(&items));
+    }
+
+    #[test]
+    fn unit_is_empty() {
+        #[derive(Serialize)]
+        struct Unit {
+            unit: (),
+            some_unit: Option<()>,
+        }
+
+        let unit = Unit {
+            unit: (),
+            some_unit: Some(()),
+        };
+        assert_eq!("{}", json(&unit));
+    }
+
+    fn cbor<T: Serialize>(value: &T) -> Vec<u8> {
+        let mut cbor = Vec::new();
+        ciborium::into_writer(value, &mut cbor).unwrap();
+        cbor
+    }
+
+    #[test]
+    fn cbor_lengths() {
+        // A map of 2 entries, rather than the 7 fields `Item` has.
+        let item = Item {
+            name: "a".into(),
+            ..Item::default()
+        };
+        let omitted = cbor(&OmitEmpty(&item));
+        assert_eq!(0xa0 + 2, omitted[0]);
+
+        #[derive(Serialize)]
+        struct Kept {
+            name: &'static str,
+            count: u32,
+        }
+        assert_eq!(
+            cbor(&Kept {
+                name: "a",
+                count: 0
+            }),
+            omitted,
+        );
+
+        // Nothing is left out, so the map's length is passed on unchanged.
+        let map = BTreeMap::from([("a", "b"), ("c", "d")]);
+        assert_eq!(cbor(&map), cbor(&OmitEmpty(&map)));
+
+        let map = BTreeMap::from([("a", ""), ("c", "d")]);
+        assert_eq!(cbor(&BTreeMap::from([("c", "d")])), cbor(&OmitEmpty(&map)));
+    }
+
+    #[test]
+    fn cbor_round_trip() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Read {
+            name: Option<String>,
+            nickname: Option<String>,
+            #[serde(default)]
+            tags: Vec<String>,
+            meta: BTreeMap<String, String>,
+            count: u32,
+            child: Option<Box<Read>>,
+        }
+
+        let item = Item {
+            tags: vec!["a".into()],
+            meta: BTreeMap::from([("b".into(), "c".into()), ("d".into(), "".into())]),
+            child: Some(Box::new(Item {
+                name: "e".into(),
+                meta: BTreeMap::from([("f".into(), "g".into())]),
+                ..Item::default()
+            })),
+            ..Item::default()
+        };
+        let read: Read = ciborium::from_reader(cbor(&OmitEmpty(&item)).as_slice()).unwrap();
+        assert_eq!(
+            Read {
+                name: None,
+                nickname: None,
+                tags: vec!["a".into()],
+                meta: BTreeMap::from([("b".into(), "c".into())]),
+                count: 0,
+                child: Some(Box::new(Read {
+                    name: Some("e".into()),
+                    nickname: None,
+                    tags: vec![],
+                    meta: BTreeMap::from([("f".into(), "g".into())]),
+                    count: 0,
+                    child: None,
+                })),
+            },
+            read,
+        );
+    }
+}
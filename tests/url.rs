@@ -0,0 +1,77 @@
+#![cfg(feature = "url")]
+
+use optempty::url::UrlNonEmpty;
+use url::Url;
+
+fn url(s: &str) -> Url {
+    Url::parse(s).unwrap()
+}
+
+#[test]
+fn query_and_fragment() {
+    let u = url("https://example.com/");
+    assert_eq!(None, u.query_non_empty());
+    assert_eq!(None, u.fragment_non_empty());
+
+    let u = url("https://example.com/?#");
+    assert_eq!(None, u.query_non_empty());
+    assert_eq!(None, u.fragment_non_empty());
+
+    let u = url("https://example.com/?a=1#top");
+    assert_eq!(Some("a=1"), u.query_non_empty());
+    assert_eq!(Some("top"), u.fragment_non_empty());
+}
+
+#[test]
+fn username_and_password() {
+    let u = url("https://example.com/");
+    assert_eq!(None, u.username_non_empty());
+    assert_eq!(None, u.password_non_empty());
+
+    let u = url("https://:secret@example.com/");
+    assert_eq!(None, u.username_non_empty());
+    assert_eq!(Some("secret"), u.password_non_empty());
+
+    let u = url("https://zoe:@example.com/");
+    assert_eq!(Some("zoe"), u.username_non_empty());
+    assert_eq!(None, u.password_non_empty());
+}
+
+#[test]
+fn path_segments_non_empty() {
+    let segments = |s| {
+        url(s)
+            .path_segments_non_empty()
+            .map(str::to_owned)
+            .collect::<Vec<_>>()
+    };
+
+    assert!(segments("https://example.com/").is_empty());
+    assert_eq!(vec!["a", "b"], segments("https://example.com//a//b/"));
+    assert_eq!(vec!["a%20b"], segments("https://example.com/a%20b"));
+    // Can't be a base, so there are no segments at all.
+    assert!(segments("mailto:zoe@example.com").is_empty());
+}
+
+#[test]
+fn query_pairs_non_empty() {
+    let u = url("https://example.com/?a=&b=1&c&=2&d=%20&b=");
+    let pairs = u
+        .query_pairs_non_empty()
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            ("b".to_owned(), "1".to_owned()),
+            (String::new(), "2".to_owned()),
+            ("d".to_owned(), " ".to_owned()),
+        ],
+        pairs
+    );
+
+    assert_eq!(
+        0,
+        url("https://example.com/").query_pairs_non_empty().count()
+    );
+}
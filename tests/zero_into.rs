@@ -0,0 +1,57 @@
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use optempty::{ZeroIntoErr, ZeroIntoNone};
+
+macro_rules! check {
+    ($($ty:ty => $non_zero:ty),*) => {
+        $(
+            assert_eq!(None::<$non_zero>, (0 as $ty).zero_into_none(), stringify!($ty));
+            assert_eq!(<$non_zero>::new(5), (5 as $ty).zero_into_none(), stringify!($ty));
+            assert_eq!(None::<$non_zero>, Some(0 as $ty).zero_into_none(), stringify!($ty));
+            assert_eq!(None::<$non_zero>, None::<$ty>.zero_into_none(), stringify!($ty));
+            assert_eq!(<$non_zero>::new(5), Some(5 as $ty).zero_into_none(), stringify!($ty));
+        )*
+    };
+}
+
+#[test]
+fn zero_into_none() {
+    check!(
+        u8 => NonZeroU8,
+        u16 => NonZeroU16,
+        u32 => NonZeroU32,
+        u64 => NonZeroU64,
+        u128 => NonZeroU128,
+        usize => NonZeroUsize,
+        i8 => NonZeroI8,
+        i16 => NonZeroI16,
+        i32 => NonZeroI32,
+        i64 => NonZeroI64,
+        i128 => NonZeroI128,
+        isize => NonZeroIsize
+    );
+
+    assert_eq!(NonZeroI32::new(-3), (-3_i32).zero_into_none());
+    assert_eq!(None, Some(Some(0_u8)).zero_into_none());
+}
+
+#[test]
+fn zero_into_err() {
+    let ok: Result<u32, &str> = Ok(0);
+    assert_eq!(Err("zero"), ok.zero_into_err(|| "zero"));
+
+    let ok: Result<u32, &str> = Ok(1);
+    assert_eq!(Ok(1), ok.zero_into_err(|| "zero"));
+
+    let err: Result<u32, &str> = Err("failed");
+    assert_eq!(Err("failed"), err.zero_into_err(|| "zero"));
+
+    let ok: Result<f64, &str> = Ok(-0.0);
+    assert_eq!(Err("zero"), ok.zero_into_err(|| "zero"));
+
+    let ok: Result<std::time::Duration, &str> = Ok(std::time::Duration::ZERO);
+    assert_eq!(Err("zero"), ok.zero_into_err(|| "zero"));
+}